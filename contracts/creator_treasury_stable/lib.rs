@@ -16,7 +16,7 @@
 // Import ink! framework components
 use ink::prelude::string::String;
 use ink::prelude::vec::Vec;
-use ink::storage::Mapping;
+use ink::storage::{Lazy, Mapping};
 
 // 📊 DATA STRUCTURES
 // These define the shape of data we store on the blockchain
@@ -37,6 +37,76 @@ pub struct CreatorProfile {
     pub total_earned: u128,
     /// Timestamp when creator registered (Unix timestamp in milliseconds)
     pub created_at: u64,
+    /// Number of subscription plans this creator has defined
+    pub plan_count: u32,
+    /// Number of catalogue items this creator has ever added
+    pub content_count: u32,
+}
+
+/// BillingPeriod is how often a plan charges its price
+/// Calendar periods use the average Gregorian month/year (365.2425 days)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub enum BillingPeriod {
+    /// Every 7 days
+    Weekly,
+    /// Every 30 days
+    Monthly,
+    /// Every 90 days
+    Quarterly,
+    /// Every 365 days
+    Yearly,
+    /// Every average calendar month (30.436875 days)
+    CalendarMonth,
+    /// Every average calendar year (365.2425 days)
+    CalendarYear,
+    /// Custom period of the given number of days
+    Days(u32),
+}
+
+impl BillingPeriod {
+    const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+    /// Length of one period in milliseconds (0 for Days(0))
+    pub fn duration_ms(&self) -> u64 {
+        match self {
+            BillingPeriod::Weekly => 7 * Self::DAY_MS,
+            BillingPeriod::Monthly => 30 * Self::DAY_MS,
+            BillingPeriod::Quarterly => 90 * Self::DAY_MS,
+            BillingPeriod::Yearly => 365 * Self::DAY_MS,
+            BillingPeriod::CalendarMonth => 2_629_746_000,
+            BillingPeriod::CalendarYear => 31_556_952_000,
+            BillingPeriod::Days(days) => u64::from(*days) * Self::DAY_MS,
+        }
+    }
+}
+
+/// SubscriptionPlan is a named tier that a creator offers to fans
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct SubscriptionPlan {
+    /// Display name of the tier (e.g., "Supporter", "Pro", "Patron")
+    pub name: String,
+    /// Price of one billing period
+    pub price: u128,
+    /// How often the price is charged
+    pub period: BillingPeriod,
+    /// Short descriptions of what subscribers on this plan get
+    pub perks: Vec<String>,
+    /// Whether new fans can still subscribe to this plan
+    pub active: bool,
+    /// Access level; content requiring tier N is open to plans with tier >= N
+    pub tier: u32,
+    /// Billing periods a fan must deposit and can't get back by cancelling early
+    pub min_commitment_periods: u32,
 }
 
 /// Subscription represents a fan's ongoing payment to a creator
@@ -47,16 +117,463 @@ pub struct CreatorProfile {
     derive(ink::storage::traits::StorageLayout)
 )]
 pub struct Subscription {
-    /// Total DOT deposited by fan for this subscription
+    /// Id of the creator's plan this subscription was opened on
+    pub plan_id: u32,
+    /// Total DOT deposited by fan for this subscription (never decreases)
+    pub total_deposited: u128,
+    /// Total DOT vested to the creator up to settled_until
+    pub total_vested: u128,
+    /// Total DOT paid out to the creator
+    pub total_claimed: u128,
+    /// Total DOT returned to the fan
+    pub total_refunded: u128,
+    /// DOT streamed to the creator per billing period (rate numerator)
+    pub price_per_period: u128,
+    /// Billing period copied from the plan (rate denominator)
+    pub billing_period: BillingPeriod,
+    /// Amount the creator is owed even if the fan cancels right away
+    pub committed: u128,
+    /// Fraction of a Planck carried between settlements, over the period length
+    pub vesting_remainder: u128,
+    /// Vesting has been accounted for up to this time (Unix timestamp)
+    /// In the future during a free trial - nothing vests until then
+    pub settled_until: u64,
+    /// When subscription started (Unix timestamp)
+    pub start_time: u64,
+    /// Who paid, if not the fan (a gift or sponsored seat) - gets the refund
+    pub payer: Option<ink::primitives::AccountId>,
+}
+
+// Subscription ledger:
+//   total_deposited = total_claimed + total_refunded + outstanding
+//   outstanding     = claimable (vested, unclaimed) + unvested
+impl Subscription {
+    /// DOT vested to the creator but not claimed yet
+    pub fn claimable(&self) -> u128 {
+        self.total_vested.saturating_sub(self.total_claimed)
+    }
+
+    /// DOT that hasn't streamed yet (refundable to the fan)
+    pub fn unvested(&self) -> u128 {
+        self.total_deposited
+            .saturating_sub(self.total_vested)
+            .saturating_sub(self.total_refunded)
+    }
+
+    /// DOT the contract still holds for this subscription
+    pub fn outstanding(&self) -> u128 {
+        self.total_deposited
+            .saturating_sub(self.total_claimed)
+            .saturating_sub(self.total_refunded)
+    }
+
+    /// Brings total_vested up to date with `now` at the exact rate
+    /// price_per_period / billing period, carrying fractions of a Planck forward
+    pub fn settle(&mut self, now: u64) {
+        let period_ms = u128::from(self.billing_period.duration_ms());
+        if now <= self.settled_until || period_ms == 0 {
+            return;
+        }
+
+        let elapsed_ms = u128::from(now - self.settled_until);
+        let accrued = self
+            .price_per_period
+            .saturating_mul(elapsed_ms)
+            .saturating_add(self.vesting_remainder);
+        let vested_amount = accrued / period_ms;
+        let unvested = self.unvested();
+
+        // A dry stream drops its remainder; nothing accrues until a top-up
+        if vested_amount >= unvested {
+            self.total_vested += unvested;
+            self.vesting_remainder = 0;
+        } else {
+            self.total_vested += vested_amount;
+            self.vesting_remainder = accrued % period_ms;
+        }
+        self.settled_until = now;
+    }
+
+    /// Timestamp when the unvested balance will have fully streamed
+    /// (settled_until once nothing is left, u64::MAX for a zero rate)
+    pub fn paid_until(&self) -> u64 {
+        let unvested = self.unvested();
+        if unvested == 0 {
+            return self.settled_until;
+        }
+        if self.price_per_period == 0 {
+            return u64::MAX;
+        }
+        let needed = unvested
+            .saturating_mul(u128::from(self.billing_period.duration_ms()))
+            .saturating_sub(self.vesting_remainder);
+        let millis_left = needed.div_ceil(self.price_per_period);
+        self.settled_until
+            .saturating_add(u64::try_from(millis_left).unwrap_or(u64::MAX))
+    }
+
+    /// Verifies deposited = claimed + refunded + outstanding can hold
+    pub fn check_ledger(&self) -> Result<(), Error> {
+        let spent = self
+            .total_vested
+            .checked_add(self.total_refunded)
+            .ok_or(Error::Overflow)?;
+        if self.total_claimed > self.total_vested || spent > self.total_deposited {
+            return Err(Error::LedgerImbalance);
+        }
+        Ok(())
+    }
+}
+
+/// Seconds in a legacy subscription month (version 1 rates were per second)
+pub const LEGACY_SECONDS_PER_PERIOD: u128 = 30 * 24 * 60 * 60;
+
+/// CreatorProfile as stored by storage version 1 (before plans and catalogue)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct CreatorProfileV1 {
+    pub name: String,
+    pub content_hash: Option<String>,
+    pub total_earned: u128,
+    pub created_at: u64,
+}
+
+impl From<CreatorProfileV1> for CreatorProfile {
+    fn from(legacy: CreatorProfileV1) -> Self {
+        Self {
+            name: legacy.name,
+            content_hash: legacy.content_hash,
+            total_earned: legacy.total_earned,
+            created_at: legacy.created_at,
+            plan_count: 0,
+            content_count: 0,
+        }
+    }
+}
+
+/// Subscription as stored by storage version 1 (total_deposited was the
+/// remaining balance)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct SubscriptionV1 {
     pub total_deposited: u128,
-    /// How much DOT flows to creator per second
     pub rate_per_second: u128,
-    /// Last time creator claimed earnings (Unix timestamp)
     pub last_claim_time: u64,
-    /// When subscription started (Unix timestamp)
     pub start_time: u64,
 }
 
+impl SubscriptionV1 {
+    /// The per-second rate expressed per 30 day period
+    pub fn price_per_period(&self) -> Result<u128, Error> {
+        self.rate_per_second
+            .checked_mul(LEGACY_SECONDS_PER_PERIOD)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Converts to the current layout on `plan_id`, streaming the remaining
+    /// balance at the same rate from the last claim
+    pub fn into_current(self, plan_id: u32) -> Result<Subscription, Error> {
+        let price_per_period = self.price_per_period()?;
+
+        Ok(Subscription {
+            plan_id,
+            total_deposited: self.total_deposited,
+            total_vested: 0,
+            total_claimed: 0,
+            total_refunded: 0,
+            price_per_period,
+            billing_period: BillingPeriod::Days(30),
+            committed: 0,
+            vesting_remainder: 0,
+            settled_until: self.last_claim_time,
+            start_time: self.start_time,
+            payer: None,
+        })
+    }
+}
+
+/// Why a subscription stopped streaming
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub enum SubscriptionEnd {
+    /// Fan cancelled and was refunded the unused balance
+    Cancelled,
+    /// The whole deposit streamed to the creator
+    Depleted,
+}
+
+/// Compact record of a subscription that has ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct ArchivedSubscription {
+    /// Plan the subscription was on
+    pub plan_id: u32,
+    /// When the subscription started (Unix timestamp)
+    pub start_time: u64,
+    /// When the subscription was closed (Unix timestamp)
+    pub ended_at: u64,
+    /// Total DOT the fan deposited over the subscription's lifetime
+    pub total_deposited: u128,
+    /// Total DOT paid to the creator
+    pub total_claimed: u128,
+    /// Amount of DOT returned to the fan when it was closed
+    pub refunded: u128,
+    /// Whether the fan cancelled or the deposit ran out
+    pub reason: SubscriptionEnd,
+}
+
+/// How the frontend should render a catalogue item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub enum ContentType {
+    /// Video file or stream
+    Video,
+    /// Music, podcast or other audio
+    Audio,
+    /// Picture or gallery
+    Image,
+    /// Written post
+    Article,
+    /// Any other downloadable file
+    Download,
+}
+
+/// One piece of exclusive content in a creator's catalogue
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct ContentItem {
+    /// Id of the item within the creator's catalogue
+    pub id: u32,
+    /// IPFS content identifier (only returned to fans with access)
+    pub cid: String,
+    /// Display title
+    pub title: String,
+    /// What kind of content the CID points to
+    pub content_type: ContentType,
+    /// When the item was added (Unix timestamp)
+    pub created_at: u64,
+    /// Minimum plan tier needed to open the item (0 = any subscriber)
+    pub required_tier: u32,
+    /// False once the creator unpublishes the item
+    pub published: bool,
+}
+
+/// Public part of a ContentItem (no CID), returned by catalogue listings
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct ContentPreview {
+    /// Id of the item within the creator's catalogue
+    pub id: u32,
+    /// Display title
+    pub title: String,
+    /// What kind of content the item is
+    pub content_type: ContentType,
+    /// When the item was added (Unix timestamp)
+    pub created_at: u64,
+    /// Minimum plan tier needed to open the item
+    pub required_tier: u32,
+    /// One-time price to buy the item outright (None = subscribers only)
+    pub price: Option<u128>,
+}
+
+impl From<&ContentItem> for ContentPreview {
+    fn from(item: &ContentItem) -> Self {
+        ContentPreview {
+            id: item.id,
+            title: item.title.clone(),
+            content_type: item.content_type,
+            created_at: item.created_at,
+            required_tier: item.required_tier,
+            price: None,
+        }
+    }
+}
+
+/// Privilege handed out for platform administration (owner holds them all)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub enum Role {
+    /// Day-to-day operations; manages Moderator and FeeManager roles
+    Admin,
+    /// Reviews creators and content
+    Moderator,
+    /// Sets the platform fee and withdraws collected fees
+    FeeManager,
+}
+
+/// Whether a subscription is running, computed at query time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub enum SubscriptionStatus {
+    /// Deposit is still streaming to the creator
+    Active,
+    /// The whole deposit has streamed
+    Depleted,
+    /// The fan cancelled and was refunded
+    Cancelled,
+    /// Contract is paused: still streaming, but no top-ups or claims until unpaused
+    Paused,
+}
+
+/// Point-in-time summary returned by get_subscription_status()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct SubscriptionOverview {
+    /// Current state of the subscription
+    pub status: SubscriptionStatus,
+    /// DOT vested to the creator but not claimed yet
+    pub claimable: u128,
+    /// DOT still left to stream (refundable on cancel, less any unmet commitment)
+    pub remaining: u128,
+    /// When the deposit runs out, or when an ended subscription was closed
+    pub paid_until: u64,
+}
+
+/// How much a coupon takes off a plan's price
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub enum Discount {
+    /// Percentage off every period (1 to 99)
+    Percent(u8),
+    /// Flat amount off every period
+    Fixed(u128),
+}
+
+impl Discount {
+    /// Price of one period after the discount (None if nothing is left to pay)
+    pub fn apply(&self, price: u128) -> Option<u128> {
+        let discounted = match *self {
+            Discount::Percent(percent) => {
+                price.checked_mul(u128::from(100 - percent.min(100)))? / 100
+            }
+            Discount::Fixed(amount) => price.saturating_sub(amount),
+        };
+        (discounted > 0).then_some(discounted)
+    }
+}
+
+/// A creator's promotion, stored under the Keccak-256 hash of its code
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct Coupon {
+    /// What the coupon takes off every period of the subscription
+    pub discount: Discount,
+    /// How many subscriptions can use the coupon (0 = unlimited)
+    pub max_redemptions: u32,
+    /// How many subscriptions have used it so far
+    pub redemptions: u32,
+    /// Coupon stops working at this time (Unix timestamp)
+    pub expires_at: Option<u64>,
+}
+
+/// A sponsor's budget of subscription seats on one creator plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct SponsorshipPool {
+    /// Account that funds the pool and manages its beneficiaries
+    pub sponsor: ink::primitives::AccountId,
+    /// Creator the seats are for
+    pub creator: ink::primitives::AccountId,
+    /// Plan every seat subscribes to
+    pub plan_id: u32,
+    /// Billing periods each seat pays for
+    pub periods_per_seat: u32,
+    /// DOT left for new seats
+    pub balance: u128,
+    /// Seats claimed so far
+    pub seats_claimed: u32,
+    /// Single-use invite codes not yet claimed or revoked
+    pub open_invites: u32,
+    /// False once the sponsor closes the pool
+    pub active: bool,
+}
+
+/// Snapshot of a creator's numbers, returned by get_creator_stats()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct CreatorStats {
+    /// Everything paid out to the creator (streams and tips, after fees)
+    pub total_earned: u128,
+    /// Fans with a live subscription
+    pub subscriber_count: u32,
+    /// Published catalogue items
+    pub published_content: u32,
+    /// Number of tips received
+    pub tip_count: u32,
+    /// DOT received as tips (after fees)
+    pub total_tips: u128,
+}
+
+/// One collaborator's cut of a creator's earnings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct RevenueShare {
+    /// Wallet that receives this share of every payout
+    pub payee: ink::primitives::AccountId,
+    /// Share of each payout in basis points
+    pub share_bps: u16,
+}
+
 /// Custom error types for our contract
 #[derive(Debug, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -65,23 +582,114 @@ pub enum Error {
     CreatorAlreadyExists,
     /// Trying to access a creator that doesn't exist
     CreatorNotFound,
-    /// Fan is already subscribed to this creator
+    /// Fan already has a funded subscription to this creator (use top_up)
     SubscriptionAlreadyExists,
     /// Trying to access a subscription that doesn't exist
     SubscriptionNotFound,
-    /// Fan doesn't have active subscription to access content
+    /// Fan has never subscribed to this creator
     SubscriptionRequired,
+    /// Fan's subscription ran out or was cancelled
+    SubscriptionExpired,
+    /// Fan's plan tier is below what the content requires
+    TierTooLow,
+    /// Catalogue item doesn't exist or has been unpublished
+    ContentNotFound,
+    /// Catalogue item has an empty CID
+    InvalidContent,
     /// Not enough DOT sent with the transaction
     InsufficientPayment,
+    /// Payment is not a whole number of billing periods of the plan
+    PaymentMismatch,
+    /// Trying to access a plan that doesn't exist for this creator
+    PlanNotFound,
+    /// Plan has been retired and no longer accepts new subscribers
+    PlanInactive,
+    /// Plan has a zero price or zero-length billing period
+    InvalidPlan,
+    /// Creator already has the maximum number of plans
+    TooManyPlans,
+    /// Subscription ledger would break deposited = claimed + refunded + outstanding
+    LedgerImbalance,
     /// Mathematical overflow (safety check)
     Overflow,
     /// Transfer of DOT failed
     TransferFailed,
+    /// Caller is not the contract owner
+    NotOwner,
+    /// Caller is not the pending owner of an ownership transfer
+    NotPendingOwner,
+    /// Caller lacks the role this action requires
+    MissingRole,
+    /// Contract is paused - only cancellations are allowed
+    ContractPaused,
+    /// Platform fee is above MAX_PLATFORM_FEE_BPS
+    FeeTooHigh,
+    /// Runtime rejected the new code hash
+    UpgradeFailed,
+    /// Storage is already at the current STORAGE_VERSION
+    AlreadyMigrated,
+    /// Split shares don't add up to 100%, or a payee is repeated or zero
+    InvalidSplit,
+    /// Revenue split has more than MAX_SPLIT_PAYEES payees
+    TooManyPayees,
+    /// Content price must be above zero
+    InvalidPrice,
+    /// Catalogue item has no one-time price
+    NotForSale,
+    /// Fan already bought this catalogue item
+    AlreadyPurchased,
+    /// Creator has no coupon with this code
+    CouponNotFound,
+    /// Coupon is past its expiry time
+    CouponExpired,
+    /// Coupon has reached its maximum number of redemptions
+    CouponExhausted,
+    /// Coupon already exists, has an out-of-range discount, or makes the plan free
+    InvalidCoupon,
+    /// Gifted or sponsored subscriptions can't be topped up by the fan
+    GiftedSubscription,
+    /// Gift recipient is the payer
+    InvalidRecipient,
+    /// Sponsorship pool doesn't exist
+    PoolNotFound,
+    /// Sponsorship pool was closed
+    PoolClosed,
+    /// Caller is not the pool's sponsor
+    NotSponsor,
+    /// Caller is neither allowlisted nor holding a valid invite code
+    NotInvited,
+    /// Caller already claimed a seat from this pool
+    SeatAlreadyClaimed,
+    /// Pool balance can't cover another seat
+    PoolExhausted,
 }
 
+/// Maximum number of plans a single creator can define
+pub const MAX_PLANS_PER_CREATOR: u32 = 16;
+
+/// Maximum number of entries returned by one page of a list query
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Highest platform fee the owner can set, in basis points (1,000 = 10%)
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Longest free trial a plan can offer, in days
+pub const MAX_TRIAL_DAYS: u32 = 90;
+
+/// Maximum number of payees in a creator's revenue split
+pub const MAX_SPLIT_PAYEES: u32 = 10;
+
+/// Layout version of the records this code reads and writes
+/// (no version cell in storage means version 1)
+pub const STORAGE_VERSION: u32 = 2;
+
 #[ink::contract]
 mod creator_treasury_stable {
     use super::*;
+    use ink::storage::traits::StorageKey;
 
     // 🎪 BLOCKCHAIN EVENTS
     // Events are like notifications that the frontend can listen to
@@ -105,10 +713,53 @@ mod creator_treasury_stable {
         /// Creator's wallet address (indexed)
         #[ink(topic)]
         pub creator: AccountId,
-        /// Monthly rate in DOT (for display)
-        pub monthly_rate: u128,
+        /// Plan the fan subscribed to
+        pub plan_id: u32,
         /// Total DOT deposited
         pub total_deposited: u128,
+        /// End of the free trial, if the fan got one
+        pub trial_ends_at: Option<u64>,
+    }
+
+    /// Emitted when a creator defines a new subscription plan
+    #[ink::event]
+    pub struct PlanCreated {
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Id of the new plan
+        pub plan_id: u32,
+        /// Price of one billing period
+        pub price: u128,
+        /// How often the price is charged
+        pub period: BillingPeriod,
+    }
+
+    /// Emitted when a creator edits or retires a subscription plan
+    #[ink::event]
+    pub struct PlanUpdated {
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Id of the updated plan
+        pub plan_id: u32,
+        /// Whether the plan still accepts new subscribers
+        pub active: bool,
+    }
+
+    /// Emitted when a fan adds funds to an existing subscription
+    #[ink::event]
+    pub struct SubscriptionToppedUp {
+        /// Fan's wallet address (indexed)
+        #[ink(topic)]
+        pub fan: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Amount of DOT added
+        pub amount: u128,
+        /// Timestamp when the extended deposit runs out
+        pub paid_until: u64,
     }
 
     /// Emitted when a creator claims their earnings
@@ -124,6 +775,28 @@ mod creator_treasury_stable {
         pub amount: u128,
     }
 
+    /// Emitted when a creator claims from many subscribers in one transfer
+    #[ink::event]
+    pub struct BatchEarningsClaimed {
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Total DOT claimed across the batch
+        pub amount: u128,
+        /// Number of subscriptions in the batch that had earnings to claim
+        pub subscriptions: u32,
+    }
+
+    /// Emitted when a creator claims earnings held back during a pause
+    #[ink::event]
+    pub struct HeldEarningsClaimed {
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Amount the creator received, after the platform fee
+        pub amount: u128,
+    }
+
     /// Emitted when a fan cancels their subscription
     #[ink::event]
     pub struct SubscriptionCancelled {
@@ -147,30 +820,404 @@ mod creator_treasury_stable {
         pub content_hash: String,
     }
 
-    /// The main contract storage
-    #[ink(storage)]
-    pub struct CreatorTreasuryStable {
-        /// Maps creator wallet address → their profile information
-        creators: Mapping<AccountId, CreatorProfile>,
-        /// Maps (fan_address, creator_address) → subscription details
-        subscriptions: Mapping<(AccountId, AccountId), Subscription>,
-        /// Total number of registered creators
-        creator_count: u32,
+    /// Emitted when a fan sends a creator a one-off tip
+    #[ink::event]
+    pub struct TipReceived {
+        /// Fan's wallet address (indexed)
+        #[ink(topic)]
+        pub fan: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Amount the creator received, after the platform fee
+        pub amount: u128,
+        /// IPFS hash of the fan's note, if any
+        pub message_cid: Option<String>,
     }
 
-    impl CreatorTreasuryStable {
-        /// Constructor - Called once when contract is deployed
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                creators: Mapping::default(),
-                subscriptions: Mapping::default(),
-                creator_count: 0,
-            }
-        }
-
-        /// Creator registers on the platform
-        #[ink(message)]
+    /// Emitted when a creator prices a catalogue item (or takes it off sale)
+    #[ink::event]
+    pub struct ContentPriceUpdated {
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Id of the catalogue item
+        pub item_id: u32,
+        /// One-time price, or None if the item is no longer for sale
+        pub price: Option<u128>,
+    }
+
+    /// Emitted when a fan buys a catalogue item outright
+    #[ink::event]
+    pub struct ContentPurchased {
+        /// Fan's wallet address (indexed)
+        #[ink(topic)]
+        pub fan: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Id of the item bought
+        pub item_id: u32,
+        /// Amount the creator received, after the platform fee
+        pub amount: u128,
+    }
+
+    /// Emitted when someone pays for a subscription on a fan's behalf
+    #[ink::event]
+    pub struct SubscriptionGifted {
+        /// Account that paid for the gift (indexed)
+        #[ink(topic)]
+        pub payer: AccountId,
+        /// Fan who received the subscription (indexed)
+        #[ink(topic)]
+        pub fan: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Plan the fan was subscribed to
+        pub plan_id: u32,
+        /// Total DOT deposited
+        pub total_deposited: u128,
+    }
+
+    /// Emitted when a sponsor opens a sponsorship pool
+    #[ink::event]
+    pub struct SponsorshipPoolCreated {
+        /// Id of the new pool
+        pub pool_id: u32,
+        /// Sponsor's wallet address (indexed)
+        #[ink(topic)]
+        pub sponsor: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Plan every seat subscribes to
+        pub plan_id: u32,
+        /// DOT funding the pool
+        pub balance: u128,
+    }
+
+    /// Emitted when a beneficiary claims a seat from a pool
+    #[ink::event]
+    pub struct SponsorshipClaimed {
+        /// Pool that paid for the seat
+        pub pool_id: u32,
+        /// Beneficiary's wallet address (indexed)
+        #[ink(topic)]
+        pub fan: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// DOT moved from the pool into the subscription
+        pub amount: u128,
+    }
+
+    /// Emitted when a sponsor closes a pool
+    #[ink::event]
+    pub struct SponsorshipPoolClosed {
+        /// Id of the closed pool
+        pub pool_id: u32,
+        /// Sponsor's wallet address (indexed)
+        #[ink(topic)]
+        pub sponsor: AccountId,
+        /// Balance returned to the sponsor
+        pub refunded: u128,
+    }
+
+    /// Emitted when a fan subscribes with a coupon
+    #[ink::event]
+    pub struct CouponRedeemed {
+        /// Fan's wallet address (indexed)
+        #[ink(topic)]
+        pub fan: AccountId,
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// Keccak-256 hash of the coupon code
+        pub code_hash: [u8; 32],
+        /// Plan the fan subscribed to
+        pub plan_id: u32,
+        /// Discounted price of one period
+        pub price_per_period: u128,
+        /// Redemptions so far, including this one
+        pub redemptions: u32,
+    }
+
+    /// Emitted when the owner changes the platform fee
+    #[ink::event]
+    pub struct PlatformFeeUpdated {
+        /// Fee before the change, in basis points
+        pub previous_fee_bps: u16,
+        /// New fee, in basis points (100 = 1%)
+        pub fee_bps: u16,
+    }
+
+    /// Emitted when the owner points fee withdrawals at a new address
+    #[ink::event]
+    pub struct TreasuryUpdated {
+        /// New treasury address (indexed)
+        #[ink(topic)]
+        pub treasury: AccountId,
+    }
+
+    /// Emitted when accumulated platform fees are sent to the treasury
+    #[ink::event]
+    pub struct PlatformFeesWithdrawn {
+        /// Treasury address that received the fees (indexed)
+        #[ink(topic)]
+        pub treasury: AccountId,
+        /// Amount of DOT withdrawn
+        pub amount: u128,
+    }
+
+    /// Emitted when the owner nominates a new owner (step one of two)
+    #[ink::event]
+    pub struct OwnershipTransferStarted {
+        /// Current owner (indexed)
+        #[ink(topic)]
+        pub owner: AccountId,
+        /// Account that must call accept_ownership() (indexed)
+        #[ink(topic)]
+        pub pending_owner: AccountId,
+    }
+
+    /// Emitted when the pending owner accepts ownership (step two of two)
+    #[ink::event]
+    pub struct OwnershipTransferred {
+        /// Previous owner (indexed)
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        /// New owner (indexed)
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Emitted when a role is granted to an account
+    #[ink::event]
+    pub struct RoleGranted {
+        /// Account that received the role (indexed)
+        #[ink(topic)]
+        pub account: AccountId,
+        /// Role that was granted
+        pub role: Role,
+        /// Owner or admin who granted it
+        pub granted_by: AccountId,
+    }
+
+    /// Emitted when a role is taken away from an account
+    #[ink::event]
+    pub struct RoleRevoked {
+        /// Account that lost the role (indexed)
+        #[ink(topic)]
+        pub account: AccountId,
+        /// Role that was revoked
+        pub role: Role,
+        /// Owner or admin who revoked it
+        pub revoked_by: AccountId,
+    }
+
+    /// Emitted when an admin pauses the contract
+    #[ink::event]
+    pub struct Paused {
+        /// Admin who pulled the switch (indexed)
+        #[ink(topic)]
+        pub by: AccountId,
+    }
+
+    /// Emitted when an admin unpauses the contract
+    #[ink::event]
+    pub struct Unpaused {
+        /// Admin who lifted the pause (indexed)
+        #[ink(topic)]
+        pub by: AccountId,
+    }
+
+    /// Emitted when a creator changes how their earnings are split
+    #[ink::event]
+    pub struct RevenueSplitUpdated {
+        /// Creator's wallet address (indexed)
+        #[ink(topic)]
+        pub creator: AccountId,
+        /// New payees and their shares (empty = creator keeps everything)
+        pub payees: Vec<RevenueShare>,
+    }
+
+    /// Emitted when a payee withdraws payouts whose transfer had failed
+    #[ink::event]
+    pub struct PayeeBalanceWithdrawn {
+        /// Payee's wallet address (indexed)
+        #[ink(topic)]
+        pub payee: AccountId,
+        /// Amount of DOT withdrawn
+        pub amount: u128,
+    }
+
+    /// Emitted when the owner swaps the contract code
+    #[ink::event]
+    pub struct Upgraded {
+        /// Hash of the new contract code
+        pub code_hash: Hash,
+    }
+
+    /// Emitted when the owner marks a storage migration as finished
+    #[ink::event]
+    pub struct StorageMigrated {
+        /// Storage version before the migration
+        pub from_version: u32,
+        /// Storage version now in use
+        pub to_version: u32,
+    }
+
+    /// The main contract storage
+    /// Plain fields share the root cell, which must keep version 1's layout
+    /// (`creator_count` only); later fields live in Mappings or Lazy cells
+    #[ink(storage)]
+    pub struct CreatorTreasuryStable {
+        /// Maps creator wallet address → their profile information
+        creators: Mapping<AccountId, CreatorProfile>,
+        /// Maps (fan_address, creator_address) → subscription details
+        subscriptions: Mapping<(AccountId, AccountId), Subscription>,
+        /// Maps (creator_address, plan_id) → plan details
+        plans: Mapping<(AccountId, u32), SubscriptionPlan>,
+        /// Maps (fan_address, creator_address, index) → ended subscription
+        subscription_history: Mapping<(AccountId, AccountId, u32), ArchivedSubscription>,
+        /// Maps (fan_address, creator_address) → number of archived subscriptions
+        history_count: Mapping<(AccountId, AccountId), u32>,
+        /// Maps creator id → creator address, in registration order
+        creator_ids: Mapping<u32, AccountId>,
+        /// Maps (creator_address, index) → fan with a live subscription
+        subscribers: Mapping<(AccountId, u32), AccountId>,
+        /// Maps creator_address → number of entries in `subscribers`
+        subscriber_count: Mapping<AccountId, u32>,
+        /// Maps (fan_address, index) → creator the fan subscribes to
+        fan_subscriptions: Mapping<(AccountId, u32), AccountId>,
+        /// Maps fan_address → number of entries in `fan_subscriptions`
+        fan_subscription_count: Mapping<AccountId, u32>,
+        /// Maps (fan_address, creator_address) → (creator list index, fan list index)
+        index_positions: Mapping<(AccountId, AccountId), (u32, u32)>,
+        /// Maps (creator_address, item_id) → catalogue item
+        content_items: Mapping<(AccountId, u32), ContentItem>,
+        /// Maps (creator_address, index) → id of a published item
+        published_content: Mapping<(AccountId, u32), u32>,
+        /// Maps creator_address → number of entries in `published_content`
+        published_count: Mapping<AccountId, u32>,
+        /// Maps (creator_address, item_id) → index in `published_content`
+        content_positions: Mapping<(AccountId, u32), u32>,
+        /// Total number of registered creators
+        creator_count: u32,
+        /// Account with full control of the contract (initially the deployer)
+        owner: Lazy<AccountId>,
+        /// Account nominated by transfer_ownership() that hasn't accepted yet
+        pending_owner: Lazy<Option<AccountId>>,
+        /// Maps (account, role) → true for every granted role
+        roles: Mapping<(AccountId, Role), bool>,
+        /// Address that receives withdrawn platform fees
+        treasury: Lazy<AccountId>,
+        /// Share of every creator payout kept by the platform, in basis points
+        platform_fee_bps: Lazy<u16>,
+        /// Platform fees collected but not yet withdrawn
+        platform_balance: Lazy<u128>,
+        /// Emergency circuit breaker (fans can always cancel)
+        paused: Lazy<bool>,
+        /// Maps creator_address → earnings vested in subscriptions cancelled while paused
+        held_earnings: Mapping<AccountId, u128>,
+        /// Maps (creator_address, price_per_period) → retired plan holding
+        /// migrated subscriptions at that rate
+        legacy_plans: Mapping<(AccountId, u128), u32>,
+        /// Layout version of the records in storage
+        storage_version: Lazy<u32>,
+        /// Number of migrated version 1 creators written into `creator_ids`
+        migrated_creator_ids: Lazy<u32>,
+        /// Maps creator_address → collaborators sharing their payouts
+        revenue_splits: Mapping<AccountId, Vec<RevenueShare>>,
+        /// Maps payee_address → payouts whose transfer failed, owed until withdrawn
+        payee_balances: Mapping<AccountId, u128>,
+        /// Maps creator_address → number of tips received
+        tip_count: Mapping<AccountId, u32>,
+        /// Maps creator_address → DOT received as tips (after fees)
+        total_tips: Mapping<AccountId, u128>,
+        /// Maps (creator_address, item_id) → one-time price of the item
+        content_prices: Mapping<(AccountId, u32), u128>,
+        /// Maps (fan_address, creator_address, item_id) → true once bought
+        purchases: Mapping<(AccountId, AccountId, u32), bool>,
+        /// Maps (creator_address, plan_id) → free trial length in days
+        plan_trials: Mapping<(AccountId, u32), u32>,
+        /// Maps (fan_address, creator_address) → true once the fan had a trial
+        trials_used: Mapping<(AccountId, AccountId), bool>,
+        /// Maps (creator_address, code hash) → coupon
+        coupons: Mapping<(AccountId, [u8; 32]), Coupon>,
+        /// Maps pool id → sponsorship pool
+        pools: Mapping<u32, SponsorshipPool>,
+        /// Next free pool id
+        pool_count: Lazy<u32>,
+        /// Maps (pool_id, account) → true for allowlisted beneficiaries
+        pool_allowlist: Mapping<(u32, AccountId), bool>,
+        /// Maps (pool_id, code hash) → true for each unused single-use invite code
+        pool_invites: Mapping<(u32, [u8; 32]), bool>,
+        /// Maps (pool_id, account) → true once the account claimed its seat
+        pool_seats: Mapping<(u32, AccountId), bool>,
+        /// Maps (fan_address, creator_address) → pool paying for the subscription
+        sponsored_by: Mapping<(AccountId, AccountId), u32>,
+    }
+
+    impl CreatorTreasuryStable {
+        /// Constructor - Called once when contract is deployed
+        /// The deployer becomes the owner and treasury, with no platform fee
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let owner = Self::env().caller();
+            let mut contract = Self {
+                creators: Mapping::default(),
+                subscriptions: Mapping::default(),
+                plans: Mapping::default(),
+                subscription_history: Mapping::default(),
+                history_count: Mapping::default(),
+                creator_ids: Mapping::default(),
+                subscribers: Mapping::default(),
+                subscriber_count: Mapping::default(),
+                fan_subscriptions: Mapping::default(),
+                fan_subscription_count: Mapping::default(),
+                index_positions: Mapping::default(),
+                content_items: Mapping::default(),
+                published_content: Mapping::default(),
+                published_count: Mapping::default(),
+                content_positions: Mapping::default(),
+                creator_count: 0,
+                owner: Lazy::default(),
+                pending_owner: Lazy::default(),
+                roles: Mapping::default(),
+                treasury: Lazy::default(),
+                platform_fee_bps: Lazy::default(),
+                platform_balance: Lazy::default(),
+                paused: Lazy::default(),
+                held_earnings: Mapping::default(),
+                legacy_plans: Mapping::default(),
+                storage_version: Lazy::default(),
+                migrated_creator_ids: Lazy::default(),
+                revenue_splits: Mapping::default(),
+                payee_balances: Mapping::default(),
+                tip_count: Mapping::default(),
+                total_tips: Mapping::default(),
+                content_prices: Mapping::default(),
+                purchases: Mapping::default(),
+                plan_trials: Mapping::default(),
+                trials_used: Mapping::default(),
+                coupons: Mapping::default(),
+                pools: Mapping::default(),
+                pool_count: Lazy::default(),
+                pool_allowlist: Mapping::default(),
+                pool_invites: Mapping::default(),
+                pool_seats: Mapping::default(),
+                sponsored_by: Mapping::default(),
+            };
+            contract.owner.set(&owner);
+            contract.treasury.set(&owner);
+            contract.storage_version.set(&STORAGE_VERSION);
+            contract
+        }
+
+        /// Creator registers on the platform
+        #[ink(message)]
         pub fn register_creator(&mut self, name: String) -> Result<(), Error> {
             let caller = self.env().caller();
 
@@ -188,12 +1235,15 @@ mod creator_treasury_stable {
                 content_hash: None,
                 total_earned: 0,
                 created_at: now,
+                plan_count: 0,
+                content_count: 0,
             };
 
             // Store the profile in our creators mapping
             self.creators.insert(caller, &profile);
 
-            // Increment our creator counter for stats
+            // Add the creator to the ordered index and bump the counter
+            self.creator_ids.insert(self.creator_count, &caller);
             self.creator_count += 1;
 
             // Emit event to notify frontend about new creator
@@ -223,234 +1273,2111 @@ mod creator_treasury_stable {
             self.creators.get(account).is_some()
         }
 
-        /// Fan subscribes to a creator with monthly payment
-        #[ink(message, payable)]
-        pub fn subscribe(&mut self, creator: AccountId, monthly_rate: u128) -> Result<(), Error> {
-            let fan = self.env().caller();
-            let payment = self.env().transferred_value();
-
-            // Verify the creator exists
+        /// Creator shares their earnings with collaborators (shares add up to
+        /// 10,000 bps; an empty list pays the creator everything)
+        #[ink(message)]
+        pub fn set_revenue_split(&mut self, payees: Vec<RevenueShare>) -> Result<(), Error> {
+            let creator = self.env().caller();
             if self.creators.get(creator).is_none() {
                 return Err(Error::CreatorNotFound);
             }
-
-            // Check if fan is already subscribed to this creator
-            let subscription_key = (fan, creator);
-            if self.subscriptions.get(subscription_key).is_some() {
-                return Err(Error::SubscriptionAlreadyExists);
+            if payees.len() > MAX_SPLIT_PAYEES as usize {
+                return Err(Error::TooManyPayees);
             }
 
-            // Convert payment from Balance to u128 for comparison and storage
-            let payment_u128: u128 = payment.try_into().map_err(|_| Error::Overflow)?;
-            
-            // Verify sufficient payment was sent
-            if payment_u128 < monthly_rate {
-                return Err(Error::InsufficientPayment);
+            if payees.is_empty() {
+                self.revenue_splits.remove(creator);
+            } else {
+                let mut total_bps: u128 = 0;
+                for (i, share) in payees.iter().enumerate() {
+                    let duplicate = payees[..i].iter().any(|other| other.payee == share.payee);
+                    if share.share_bps == 0 || duplicate {
+                        return Err(Error::InvalidSplit);
+                    }
+                    total_bps += u128::from(share.share_bps);
+                }
+                if total_bps != BPS_DENOMINATOR {
+                    return Err(Error::InvalidSplit);
+                }
+                self.revenue_splits.insert(creator, &payees);
             }
 
-            // Calculate streaming rate per second
-            let seconds_per_month = 30u128 * 24 * 60 * 60;
-            let rate_per_second = monthly_rate / seconds_per_month;
-
-            // Get current timestamp for subscription start
-            let now = self.env().block_timestamp();
-
-            // Create subscription record
-            let subscription = Subscription {
-                total_deposited: payment_u128,
-                rate_per_second,
-                last_claim_time: now,
-                start_time: now,
-            };
-
-            // Store subscription in mapping
-            self.subscriptions.insert(subscription_key, &subscription);
-
-            // Emit event for frontend notification
-            self.env().emit_event(SubscriptionCreated {
-                fan,
-                creator,
-                monthly_rate,
-                total_deposited: payment_u128,
-            });
+            self.env().emit_event(RevenueSplitUpdated { creator, payees });
 
             Ok(())
         }
 
-        /// Get subscription details between a fan and creator
+        /// Get a creator's revenue split (empty if the creator keeps everything)
         #[ink(message)]
-        pub fn get_subscription(&self, fan: AccountId, creator: AccountId) -> Result<Subscription, Error> {
-            let subscription_key = (fan, creator);
-            self.subscriptions
-                .get(subscription_key)
-                .ok_or(Error::SubscriptionNotFound)
+        pub fn get_revenue_split(&self, creator: AccountId) -> Vec<RevenueShare> {
+            self.revenue_splits.get(creator).unwrap_or_default()
         }
 
-        /// Creator claims their vested earnings from a specific fan
+        /// Payee withdraws payouts that couldn't be sent when they were made
         #[ink(message)]
-        pub fn claim_earnings(&mut self, fan: AccountId) -> Result<u128, Error> {
-            let creator = self.env().caller();
-            let subscription_key = (fan, creator);
+        pub fn withdraw_payee_balance(&mut self) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let payee = self.env().caller();
+            let amount = self.payee_balances.take(payee).unwrap_or(0);
+            if amount == 0 {
+                return Ok(0);
+            }
 
-            // Get subscription details
-            let mut subscription = self
-                .subscriptions
-                .get(subscription_key)
-                .ok_or(Error::SubscriptionNotFound)?;
+            if self.env().transfer(payee, amount.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
 
-            // Get current blockchain timestamp
-            let now = self.env().block_timestamp();
+            self.env().emit_event(PayeeBalanceWithdrawn { payee, amount });
 
-            // Calculate time elapsed since last claim (in milliseconds)
-            let time_elapsed = now - subscription.last_claim_time;
-            let seconds_elapsed = time_elapsed / 1000;
+            Ok(amount)
+        }
 
-            // Calculate how much has vested since last claim
-            let claimable_amount = (seconds_elapsed as u128) * subscription.rate_per_second;
+        /// Get the payouts owed to a payee whose transfers failed
+        #[ink(message)]
+        pub fn get_payee_balance(&self, payee: AccountId) -> u128 {
+            self.payee_balances.get(payee).unwrap_or(0)
+        }
 
-            // Ensure we don't claim more than what's available
-            let claimable_amount = claimable_amount.min(subscription.total_deposited);
+        /// Creator defines a new subscription plan and gets back its id
+        #[ink(message)]
+        pub fn create_plan(
+            &mut self,
+            name: String,
+            price: u128,
+            period: BillingPeriod,
+            perks: Vec<String>,
+        ) -> Result<u32, Error> {
+            let creator = self.env().caller();
+            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
 
-            // If nothing to claim, return early
-            if claimable_amount == 0 {
-                return Ok(0);
+            if price == 0 || period.duration_ms() == 0 {
+                return Err(Error::InvalidPlan);
             }
-
-            // Update subscription state to reflect the claim
-            subscription.last_claim_time = now;
-            subscription.total_deposited = subscription
-                .total_deposited
-                .saturating_sub(claimable_amount);
-
-            // Save the updated subscription state
-            self.subscriptions.insert(subscription_key, &subscription);
-
-            // Update creator's total earnings for display purposes
-            if let Some(mut profile) = self.creators.get(creator) {
-                profile.total_earned = profile.total_earned.saturating_add(claimable_amount);
-                self.creators.insert(creator, &profile);
+            if profile.plan_count >= MAX_PLANS_PER_CREATOR {
+                return Err(Error::TooManyPlans);
             }
 
-            // Transfer the claimed DOT from contract to creator's wallet
-            if self
-                .env()
-                .transfer(creator, claimable_amount.into())
-                .is_err()
-            {
-                return Err(Error::TransferFailed);
-            }
+            let plan_id = profile.plan_count;
+            let plan = SubscriptionPlan {
+                name,
+                price,
+                period,
+                perks,
+                active: true,
+                tier: 0,
+                min_commitment_periods: 0,
+            };
+            self.plans.insert((creator, plan_id), &plan);
 
-            // Emit event for frontend notification
-            self.env().emit_event(EarningsClaimed {
+            profile.plan_count += 1;
+            self.creators.insert(creator, &profile);
+
+            self.env().emit_event(PlanCreated {
                 creator,
-                fan,
-                amount: claimable_amount,
+                plan_id,
+                price,
+                period,
             });
 
-            Ok(claimable_amount)
+            Ok(plan_id)
         }
 
-        /// Fan cancels subscription and gets refund for unused time
+        /// Creator edits a plan (only affects new subscriptions)
         #[ink(message)]
-        pub fn cancel_subscription(&mut self, creator: AccountId) -> Result<u128, Error> {
-            let fan = self.env().caller();
-            let subscription_key = (fan, creator);
-
-            // Get subscription details
-            let subscription = self.subscriptions
-                .get(subscription_key)
-                .ok_or(Error::SubscriptionNotFound)?;
+        pub fn update_plan(
+            &mut self,
+            plan_id: u32,
+            name: String,
+            price: u128,
+            period: BillingPeriod,
+            perks: Vec<String>,
+        ) -> Result<(), Error> {
+            let creator = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
 
-            // Get current blockchain timestamp
-            let now = self.env().block_timestamp();
+            if price == 0 || period.duration_ms() == 0 {
+                return Err(Error::InvalidPlan);
+            }
 
-            // Calculate total time elapsed since subscription started
-            let total_elapsed = now - subscription.start_time;
-            let seconds_elapsed = total_elapsed / 1000;
+            plan.name = name;
+            plan.price = price;
+            plan.period = period;
+            plan.perks = perks;
+            self.plans.insert((creator, plan_id), &plan);
 
-            // Calculate total amount that has vested since subscription started
-            let total_vested = seconds_elapsed as u128 * subscription.rate_per_second;
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
 
-            // Calculate refund amount (what hasn't vested yet)
-            let refund_amount = if total_vested >= subscription.total_deposited {
-                0 // Subscription fully vested, no refund available
-            } else {
-                subscription.total_deposited - total_vested
-            };
+            Ok(())
+        }
 
-            // Mark subscription as cancelled by setting balance to 0
-            let cancelled_subscription = Subscription {
-                total_deposited: 0,
-                rate_per_second: 0,
-                last_claim_time: subscription.last_claim_time,
-                start_time: subscription.start_time,
-            };
-            self.subscriptions.insert(subscription_key, &cancelled_subscription);
+        /// Creator opens or closes a plan for new subscribers
+        #[ink(message)]
+        pub fn set_plan_active(&mut self, plan_id: u32, active: bool) -> Result<(), Error> {
+            let creator = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
 
-            // Transfer refund to fan if there's anything to refund
-            if refund_amount > 0 {
-                if self.env().transfer(fan, refund_amount.into()).is_err() {
-                    return Err(Error::TransferFailed);
-                }
-            }
+            plan.active = active;
+            self.plans.insert((creator, plan_id), &plan);
 
-            // Emit event for frontend notification
-            self.env().emit_event(SubscriptionCancelled {
-                fan,
+            self.env().emit_event(PlanUpdated {
                 creator,
-                refund_amount,
+                plan_id,
+                active,
             });
 
-            Ok(refund_amount)
+            Ok(())
         }
 
-        /// Creator adds exclusive content (IPFS hash)
+        /// Creator sets the access tier of a plan
         #[ink(message)]
-        pub fn add_exclusive_content(&mut self, content_hash: String) -> Result<(), Error> {
+        pub fn set_plan_tier(&mut self, plan_id: u32, tier: u32) -> Result<(), Error> {
             let creator = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
 
-            // Verify caller is a registered creator
-            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
-
-            // Update the creator's profile with the new content hash
-            profile.content_hash = Some(content_hash.clone());
-
-            // Save the updated profile back to blockchain storage
-            self.creators.insert(creator, &profile);
+            plan.tier = tier;
+            self.plans.insert((creator, plan_id), &plan);
 
-            // Emit event for frontend notification
-            self.env().emit_event(ContentAdded {
+            self.env().emit_event(PlanUpdated {
                 creator,
-                content_hash,
+                plan_id,
+                active: plan.active,
             });
 
             Ok(())
         }
 
-        /// Get creator's exclusive content (if caller is subscribed)
+        /// Creator sets how many billing periods new fans commit to (0 to disable)
         #[ink(message)]
-        pub fn get_creator_content(&self, creator: AccountId) -> Result<String, Error> {
+        pub fn set_plan_commitment(&mut self, plan_id: u32, periods: u32) -> Result<(), Error> {
+            let creator = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+
+            plan.price
+                .checked_mul(u128::from(periods))
+                .ok_or(Error::Overflow)?;
+            plan.min_commitment_periods = periods;
+            self.plans.insert((creator, plan_id), &plan);
+
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
+
+            Ok(())
+        }
+
+        /// Creator offers a free trial on a plan (0 disables it); nothing vests
+        /// during the trial and each fan gets one trial per creator
+        #[ink(message)]
+        pub fn set_plan_trial(&mut self, plan_id: u32, trial_days: u32) -> Result<(), Error> {
+            let creator = self.env().caller();
+            let plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+
+            if trial_days > MAX_TRIAL_DAYS {
+                return Err(Error::InvalidPlan);
+            }
+            if trial_days == 0 {
+                self.plan_trials.remove((creator, plan_id));
+            } else {
+                self.plan_trials.insert((creator, plan_id), &trial_days);
+            }
+
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
+
+            Ok(())
+        }
+
+        /// Get the free trial length of a plan in days (0 = no trial)
+        #[ink(message)]
+        pub fn get_plan_trial(&self, creator: AccountId, plan_id: u32) -> u32 {
+            self.plan_trials.get((creator, plan_id)).unwrap_or(0)
+        }
+
+        /// Check whether a fan has already had their free trial with a creator
+        #[ink(message)]
+        pub fn has_used_trial(&self, fan: AccountId, creator: AccountId) -> bool {
+            self.trials_used.contains((fan, creator))
+        }
+
+        /// Creator sets up a coupon under the Keccak-256 hash of its code
+        /// (percentage 1-99 or fixed discount, 0 max_redemptions = unlimited)
+        #[ink(message)]
+        pub fn create_coupon(
+            &mut self,
+            code_hash: [u8; 32],
+            discount: Discount,
+            max_redemptions: u32,
+            expires_at: Option<u64>,
+        ) -> Result<(), Error> {
+            let creator = self.env().caller();
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+
+            let valid_discount = match discount {
+                Discount::Percent(percent) => (1..100).contains(&percent),
+                Discount::Fixed(amount) => amount > 0,
+            };
+            if !valid_discount || self.coupons.contains((creator, code_hash)) {
+                return Err(Error::InvalidCoupon);
+            }
+
+            let coupon = Coupon {
+                discount,
+                max_redemptions,
+                redemptions: 0,
+                expires_at,
+            };
+            self.coupons.insert((creator, code_hash), &coupon);
+
+            Ok(())
+        }
+
+        /// Creator withdraws a coupon; subscriptions that used it keep their price
+        #[ink(message)]
+        pub fn revoke_coupon(&mut self, code_hash: [u8; 32]) -> Result<(), Error> {
+            let creator = self.env().caller();
+            self.coupons
+                .take((creator, code_hash))
+                .ok_or(Error::CouponNotFound)?;
+            Ok(())
+        }
+
+        /// Get a creator's coupon, including how often it has been redeemed
+        #[ink(message)]
+        pub fn get_coupon(&self, creator: AccountId, code_hash: [u8; 32]) -> Result<Coupon, Error> {
+            self.coupons
+                .get((creator, code_hash))
+                .ok_or(Error::CouponNotFound)
+        }
+
+        /// Hash a coupon code the same way subscribe() does (run as a query)
+        #[ink(message)]
+        pub fn hash_coupon_code(&self, code: String) -> [u8; 32] {
+            Self::hash_code(&code)
+        }
+
+        /// Get a single plan offered by a creator
+        #[ink(message)]
+        pub fn get_plan(&self, creator: AccountId, plan_id: u32) -> Result<SubscriptionPlan, Error> {
+            self.plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)
+        }
+
+        /// Get every plan a creator has defined, including retired ones
+        #[ink(message)]
+        pub fn get_creator_plans(&self, creator: AccountId) -> Vec<(u32, SubscriptionPlan)> {
+            let plan_count = self
+                .creators
+                .get(creator)
+                .map(|profile| profile.plan_count)
+                .unwrap_or(0);
+
+            (0..plan_count)
+                .filter_map(|plan_id| {
+                    self.plans
+                        .get((creator, plan_id))
+                        .map(|plan| (plan_id, plan))
+                })
+                .collect()
+        }
+
+        /// Fan subscribes to one of a creator's plans, optionally with a coupon
+        /// code whose discount applies to every period
+        #[ink(message, payable)]
+        pub fn subscribe(
+            &mut self,
+            creator: AccountId,
+            plan_id: u32,
+            coupon: Option<String>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
             let fan = self.env().caller();
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+            self.open_subscription(fan, creator, plan_id, coupon, payment, None)?;
+            Ok(())
+        }
 
-            // Verify creator exists and has content
-            let profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+        /// Pay for a subscription on someone else's behalf; the recipient gets
+        /// access and can cancel, but refunds go back to the payer
+        #[ink(message, payable)]
+        pub fn gift_subscription(
+            &mut self,
+            recipient: AccountId,
+            creator: AccountId,
+            plan_id: u32,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let payer = self.env().caller();
+            if recipient == payer {
+                return Err(Error::InvalidRecipient);
+            }
+
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+            let total_deposited =
+                self.open_subscription(recipient, creator, plan_id, None, payment, Some(payer))?;
+
+            self.env().emit_event(SubscriptionGifted {
+                payer,
+                fan: recipient,
+                creator,
+                plan_id,
+                total_deposited,
+            });
+
+            Ok(())
+        }
+
+        /// Sponsor opens a pool of seats on a creator plan, funded by the DOT sent
+        /// Each seat costs plan price × periods_per_seat
+        #[ink(message, payable)]
+        pub fn create_pool(
+            &mut self,
+            creator: AccountId,
+            plan_id: u32,
+            periods_per_seat: u32,
+        ) -> Result<u32, Error> {
+            self.ensure_not_paused()?;
+            let sponsor = self.env().caller();
+            let balance: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            let plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            if !plan.active {
+                return Err(Error::PlanInactive);
+            }
+            if periods_per_seat == 0 {
+                return Err(Error::InvalidPlan);
+            }
+
+            let pool_id = self.pool_count.get().unwrap_or(0);
+            self.pool_count
+                .set(&pool_id.checked_add(1).ok_or(Error::Overflow)?);
+            let pool = SponsorshipPool {
+                sponsor,
+                creator,
+                plan_id,
+                periods_per_seat,
+                balance,
+                seats_claimed: 0,
+                open_invites: 0,
+                active: true,
+            };
+            self.pools.insert(pool_id, &pool);
+
+            self.env().emit_event(SponsorshipPoolCreated {
+                pool_id,
+                sponsor,
+                creator,
+                plan_id,
+                balance,
+            });
+
+            Ok(pool_id)
+        }
+
+        /// Sponsor adds DOT to an open pool; returns the new balance
+        #[ink(message, payable)]
+        pub fn fund_pool(&mut self, pool_id: u32) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let amount: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            let mut pool = self.sponsor_pool(pool_id)?;
+            pool.balance = pool.balance.checked_add(amount).ok_or(Error::Overflow)?;
+            self.pools.insert(pool_id, &pool);
+
+            Ok(pool.balance)
+        }
+
+        /// Sponsor allowlists beneficiaries who may claim a seat
+        #[ink(message)]
+        pub fn add_beneficiaries(
+            &mut self,
+            pool_id: u32,
+            beneficiaries: Vec<AccountId>,
+        ) -> Result<(), Error> {
+            self.sponsor_pool(pool_id)?;
+            for beneficiary in beneficiaries {
+                self.pool_allowlist.insert((pool_id, beneficiary), &true);
+            }
+            Ok(())
+        }
+
+        /// Sponsor takes a beneficiary off the allowlist (a claimed seat keeps streaming)
+        #[ink(message)]
+        pub fn remove_beneficiary(
+            &mut self,
+            pool_id: u32,
+            beneficiary: AccountId,
+        ) -> Result<(), Error> {
+            self.sponsor_pool(pool_id)?;
+            self.pool_allowlist.remove((pool_id, beneficiary));
+            Ok(())
+        }
+
+        /// Sponsor issues single-use invite codes (Keccak-256 hashes, see
+        /// hash_coupon_code) - one per invitee, since a claim reveals its code
+        #[ink(message)]
+        pub fn add_invites(
+            &mut self,
+            pool_id: u32,
+            code_hashes: Vec<[u8; 32]>,
+        ) -> Result<(), Error> {
+            let mut pool = self.sponsor_pool(pool_id)?;
+            for code_hash in code_hashes {
+                if !self.pool_invites.contains((pool_id, code_hash)) {
+                    self.pool_invites.insert((pool_id, code_hash), &true);
+                    pool.open_invites = pool.open_invites.saturating_add(1);
+                }
+            }
+            self.pools.insert(pool_id, &pool);
+            Ok(())
+        }
+
+        /// Sponsor withdraws an unused invite code
+        #[ink(message)]
+        pub fn revoke_invite(&mut self, pool_id: u32, code_hash: [u8; 32]) -> Result<(), Error> {
+            let mut pool = self.sponsor_pool(pool_id)?;
+            if self.pool_invites.take((pool_id, code_hash)).is_some() {
+                pool.open_invites = pool.open_invites.saturating_sub(1);
+                self.pools.insert(pool_id, &pool);
+            }
+            Ok(())
+        }
+
+        /// Allowlisted caller, or one with an unused invite code (which the claim
+        /// uses up), claims one seat: a subscription paid from the pool, whose
+        /// unused balance returns to the pool on cancellation
+        #[ink(message)]
+        pub fn claim_sponsorship(
+            &mut self,
+            pool_id: u32,
+            invite_code: Option<String>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let fan = self.env().caller();
+            let mut pool = self.pools.get(pool_id).ok_or(Error::PoolNotFound)?;
+            if !pool.active {
+                return Err(Error::PoolClosed);
+            }
+
+            // Allowlisted callers need no code; anyone else uses one up
+            let invite = if self.pool_allowlist.contains((pool_id, fan)) {
+                None
+            } else {
+                let code = invite_code.ok_or(Error::NotInvited)?;
+                let invite = (pool_id, Self::hash_code(&code));
+                if !self.pool_invites.contains(invite) {
+                    return Err(Error::NotInvited);
+                }
+                Some(invite)
+            };
+            if self.pool_seats.contains((pool_id, fan)) {
+                return Err(Error::SeatAlreadyClaimed);
+            }
+
+            let plan = self
+                .plans
+                .get((pool.creator, pool.plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            let seat_cost = plan
+                .price
+                .checked_mul(u128::from(pool.periods_per_seat.max(plan.min_commitment_periods)))
+                .ok_or(Error::Overflow)?;
+            if seat_cost > pool.balance {
+                return Err(Error::PoolExhausted);
+            }
+
+            let sponsor = Some(pool.sponsor);
+            self.open_subscription(fan, pool.creator, pool.plan_id, None, seat_cost, sponsor)?;
+            self.sponsored_by.insert((fan, pool.creator), &pool_id);
+
+            if let Some(invite) = invite {
+                self.pool_invites.remove(invite);
+                pool.open_invites = pool.open_invites.saturating_sub(1);
+            }
+            pool.balance -= seat_cost;
+            pool.seats_claimed = pool.seats_claimed.saturating_add(1);
+            self.pools.insert(pool_id, &pool);
+            self.pool_seats.insert((pool_id, fan), &true);
+
+            self.env().emit_event(SponsorshipClaimed {
+                pool_id,
+                fan,
+                creator: pool.creator,
+                amount: seat_cost,
+            });
+
+            Ok(())
+        }
+
+        /// Sponsor closes a pool and takes back its balance; returns the amount
+        #[ink(message)]
+        pub fn close_pool(&mut self, pool_id: u32) -> Result<u128, Error> {
+            let mut pool = self.sponsor_pool(pool_id)?;
+            let refunded = pool.balance;
+            pool.balance = 0;
+            pool.active = false;
+            self.pools.insert(pool_id, &pool);
+
+            if refunded > 0 && self.env().transfer(pool.sponsor, refunded.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(SponsorshipPoolClosed {
+                pool_id,
+                sponsor: pool.sponsor,
+                refunded,
+            });
+
+            Ok(refunded)
+        }
+
+        /// Get a sponsorship pool
+        #[ink(message)]
+        pub fn get_pool(&self, pool_id: u32) -> Result<SponsorshipPool, Error> {
+            self.pools.get(pool_id).ok_or(Error::PoolNotFound)
+        }
+
+        /// Check whether an account is allowlisted for a pool
+        #[ink(message)]
+        pub fn is_beneficiary(&self, pool_id: u32, account: AccountId) -> bool {
+            self.pool_allowlist.contains((pool_id, account))
+        }
+
+        /// Get the pool paying for a fan's subscription, if it's sponsored
+        #[ink(message)]
+        pub fn get_sponsoring_pool(&self, fan: AccountId, creator: AccountId) -> Option<u32> {
+            self.sponsored_by.get((fan, creator))
+        }
 
-            // Check if fan has active subscription to this creator
+        /// Get subscription details between a fan and creator
+        #[ink(message)]
+        pub fn get_subscription(&self, fan: AccountId, creator: AccountId) -> Result<Subscription, Error> {
             let subscription_key = (fan, creator);
-            if self.subscriptions.get(subscription_key).is_none() {
-                return Err(Error::SubscriptionRequired);
+            self.subscriptions
+                .get(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)
+        }
+
+        /// Get the status of a fan's subscription as of now, settled on a copy
+        /// Without a live subscription the latest archived one decides the status
+        #[ink(message)]
+        pub fn get_subscription_status(
+            &self,
+            fan: AccountId,
+            creator: AccountId,
+        ) -> Result<SubscriptionOverview, Error> {
+            if let Some(mut subscription) = self.subscriptions.get((fan, creator)) {
+                // Read run-out time before settling moves a dry stream to now
+                let paid_until = subscription.paid_until();
+                subscription.settle(self.env().block_timestamp());
+
+                let remaining = subscription.unvested();
+                let status = if remaining == 0 {
+                    SubscriptionStatus::Depleted
+                } else if self.is_paused() {
+                    SubscriptionStatus::Paused
+                } else {
+                    SubscriptionStatus::Active
+                };
+
+                return Ok(SubscriptionOverview {
+                    status,
+                    claimable: subscription.claimable(),
+                    remaining,
+                    paid_until,
+                });
             }
 
-            // Return content hash if available
-            profile.content_hash.ok_or(Error::CreatorNotFound)
+            let count = self.history_count.get((fan, creator)).unwrap_or(0);
+            let last = count
+                .checked_sub(1)
+                .and_then(|index| self.subscription_history.get((fan, creator, index)))
+                .ok_or(Error::SubscriptionNotFound)?;
+            let status = match last.reason {
+                SubscriptionEnd::Cancelled => SubscriptionStatus::Cancelled,
+                SubscriptionEnd::Depleted => SubscriptionStatus::Depleted,
+            };
+
+            Ok(SubscriptionOverview {
+                status,
+                claimable: 0,
+                remaining: 0,
+                paid_until: last.ended_at,
+            })
+        }
+
+        /// Get every ended subscription between a fan and creator, oldest first
+        #[ink(message)]
+        pub fn get_subscription_history(
+            &self,
+            fan: AccountId,
+            creator: AccountId,
+        ) -> Vec<ArchivedSubscription> {
+            let count = self.history_count.get((fan, creator)).unwrap_or(0);
+            (0..count)
+                .filter_map(|index| self.subscription_history.get((fan, creator, index)))
+                .collect()
+        }
+
+        /// Creator claims their vested earnings from a specific fan
+        #[ink(message)]
+        pub fn claim_earnings(&mut self, fan: AccountId) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let creator = self.env().caller();
+            let subscription_key = (fan, creator);
+
+            // Get subscription details
+            let mut subscription = self
+                .subscriptions
+                .get(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)?;
+
+            // Bring the ledger up to date and claim everything vested
+            subscription.settle(self.env().block_timestamp());
+            let claimable_amount = subscription.claimable();
+            subscription.total_claimed += claimable_amount;
+
+            // Save the updated subscription state
+            self.save_subscription(subscription_key, &subscription)?;
+
+            // If nothing to claim, return early
+            if claimable_amount == 0 {
+                return Ok(0);
+            }
+
+            // Creator receives the claim minus the platform fee
+            let paid = self.pay_creator(creator, claimable_amount)?;
+
+            // Emit event for frontend notification
+            self.env().emit_event(EarningsClaimed {
+                creator,
+                fan,
+                amount: paid,
+            });
+
+            Ok(paid)
+        }
+
+        /// Creator claims vested earnings from every subscriber in a single
+        /// transfer (use claim_batch for large audiences)
+        #[ink(message)]
+        pub fn claim_all(&mut self) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let creator = self.env().caller();
+            let total = self.subscriber_count.get(creator).unwrap_or(0);
+            let (claimed, _) = self.claim_range(creator, 0..total)?;
+            Ok(claimed)
+        }
+
+        /// Creator claims from one batch of subscribers; returns the amount and
+        /// the offset where the next batch starts
+        #[ink(message)]
+        pub fn claim_batch(&mut self, offset: u32, limit: u32) -> Result<(u128, u32), Error> {
+            self.ensure_not_paused()?;
+            let creator = self.env().caller();
+            let total = self.subscriber_count.get(creator).unwrap_or(0);
+            self.claim_range(creator, Self::page_range(offset, limit, total))
+        }
+
+        /// Creator claims earnings held back from cancellations during a pause
+        #[ink(message)]
+        pub fn claim_held_earnings(&mut self) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let creator = self.env().caller();
+            let held = self.held_earnings.take(creator).unwrap_or(0);
+            if held == 0 {
+                return Ok(0);
+            }
+
+            let paid = self.pay_creator(creator, held)?;
+            self.env().emit_event(HeldEarningsClaimed {
+                creator,
+                amount: paid,
+            });
+
+            Ok(paid)
+        }
+
+        /// Get the earnings held for a creator until claim_held_earnings()
+        #[ink(message)]
+        pub fn get_held_earnings(&self, creator: AccountId) -> u128 {
+            self.held_earnings.get(creator).unwrap_or(0)
+        }
+
+        /// Fan adds funds to an existing subscription and gets back the
+        /// timestamp when the extended deposit runs out
+        #[ink(message, payable)]
+        pub fn top_up(&mut self, creator: AccountId) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let fan = self.env().caller();
+            let payment = self.env().transferred_value();
+
+            let payment_u128: u128 = payment.try_into().map_err(|_| Error::Overflow)?;
+            if payment_u128 == 0 {
+                return Err(Error::InsufficientPayment);
+            }
+
+            let subscription_key = (fan, creator);
+            let mut subscription = self
+                .subscriptions
+                .get(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)?;
+            if subscription.payer.is_some() {
+                return Err(Error::GiftedSubscription);
+            }
+
+            // Settle what has already vested so the new funds only stream forward
+            subscription.settle(self.env().block_timestamp());
+
+            subscription.total_deposited = subscription
+                .total_deposited
+                .checked_add(payment_u128)
+                .ok_or(Error::Overflow)?;
+            self.save_subscription(subscription_key, &subscription)?;
+
+            let paid_until = subscription.paid_until();
+
+            self.env().emit_event(SubscriptionToppedUp {
+                fan,
+                creator,
+                amount: payment_u128,
+                paid_until,
+            });
+
+            Ok(paid_until)
         }
 
-        /// Get list of all registered creators with their profiles
+        /// Fan cancels subscription and gets refund for unused time
+        /// (allowed while paused, so deposits are never stuck - the creator's
+        /// vested share is then held for claim_held_earnings())
         #[ink(message)]
-        pub fn get_creator_list(&self) -> Vec<(AccountId, CreatorProfile)> {
-            // TODO: Implement proper iteration over creators
-            // For now, return empty vector to avoid compilation issues
-            Vec::new()
+        pub fn cancel_subscription(&mut self, creator: AccountId) -> Result<u128, Error> {
+            let fan = self.env().caller();
+
+            // Settle, pay the creator their share, refund the fan and archive
+            let refund_amount =
+                self.close_subscription(fan, creator, SubscriptionEnd::Cancelled)?;
+
+            // Emit event for frontend notification
+            self.env().emit_event(SubscriptionCancelled {
+                fan,
+                creator,
+                refund_amount,
+            });
+
+            Ok(refund_amount)
+        }
+
+        /// Fan sends a creator a one-time tip, paid out straight away
+        /// (platform fee and revenue split apply); returns the net amount
+        #[ink(message, payable)]
+        pub fn tip(
+            &mut self,
+            creator: AccountId,
+            message_cid: Option<String>,
+        ) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let fan = self.env().caller();
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+            if payment == 0 {
+                return Err(Error::InsufficientPayment);
+            }
+
+            let amount = self.pay_creator(creator, payment)?;
+
+            let count = self.tip_count.get(creator).unwrap_or(0);
+            self.tip_count.insert(creator, &count.saturating_add(1));
+            let total = self.total_tips.get(creator).unwrap_or(0);
+            self.total_tips.insert(creator, &total.saturating_add(amount));
+
+            self.env().emit_event(TipReceived {
+                fan,
+                creator,
+                amount,
+                message_cid,
+            });
+
+            Ok(amount)
+        }
+
+        /// Creator adds exclusive content (IPFS hash)
+        #[ink(message)]
+        pub fn add_exclusive_content(&mut self, content_hash: String) -> Result<(), Error> {
+            let creator = self.env().caller();
+
+            // Verify caller is a registered creator
+            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            // Update the creator's profile with the new content hash
+            profile.content_hash = Some(content_hash.clone());
+
+            // Save the updated profile back to blockchain storage
+            self.creators.insert(creator, &profile);
+
+            // Emit event for frontend notification
+            self.env().emit_event(ContentAdded {
+                creator,
+                content_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Get creator's exclusive content (if caller is subscribed)
+        #[ink(message)]
+        pub fn get_creator_content(&self, creator: AccountId) -> Result<String, Error> {
+            let fan = self.env().caller();
+
+            // Verify creator exists and has content
+            let profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            // Check if fan has a live, funded subscription to this creator
+            self.check_access(fan, creator, 0)?;
+
+            // Return content hash if available
+            profile.content_hash.ok_or(Error::CreatorNotFound)
+        }
+
+        /// Get the CID of a catalogue item, opened by a subscription of its tier
+        /// or by a purchase of the item (see get_content)
+        #[ink(message)]
+        pub fn get_creator_item_content(
+            &self,
+            creator: AccountId,
+            item_id: u32,
+        ) -> Result<String, Error> {
+            self.get_content(creator, item_id).map(|item| item.cid)
+        }
+
+        /// Creator adds an item to their content catalogue and gets back its id
+        #[ink(message)]
+        pub fn add_content(
+            &mut self,
+            cid: String,
+            title: String,
+            content_type: ContentType,
+            required_tier: u32,
+        ) -> Result<u32, Error> {
+            let creator = self.env().caller();
+            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            if cid.is_empty() {
+                return Err(Error::InvalidContent);
+            }
+
+            let item_id = profile.content_count;
+            profile.content_count = item_id.checked_add(1).ok_or(Error::Overflow)?;
+
+            let item = ContentItem {
+                id: item_id,
+                cid,
+                title,
+                content_type,
+                created_at: self.env().block_timestamp(),
+                required_tier,
+                published: true,
+            };
+            self.content_items.insert((creator, item_id), &item);
+            self.creators.insert(creator, &profile);
+
+            // Append to the published list
+            let position = self.published_count.get(creator).unwrap_or(0);
+            self.published_content.insert((creator, position), &item_id);
+            self.published_count.insert(creator, &(position + 1));
+            self.content_positions.insert((creator, item_id), &position);
+
+            self.env().emit_event(ContentAdded {
+                creator,
+                content_hash: item.cid,
+            });
+
+            Ok(item_id)
+        }
+
+        /// Creator edits a published catalogue item (id and creation time are kept)
+        #[ink(message)]
+        pub fn update_content(
+            &mut self,
+            item_id: u32,
+            cid: String,
+            title: String,
+            content_type: ContentType,
+            required_tier: u32,
+        ) -> Result<(), Error> {
+            let creator = self.env().caller();
+            let mut item = self
+                .content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+
+            if cid.is_empty() {
+                return Err(Error::InvalidContent);
+            }
+
+            item.cid = cid;
+            item.title = title;
+            item.content_type = content_type;
+            item.required_tier = required_tier;
+            self.content_items.insert((creator, item_id), &item);
+
+            Ok(())
+        }
+
+        /// Creator removes an item from their catalogue (its id is never reused)
+        #[ink(message)]
+        pub fn unpublish_content(&mut self, item_id: u32) -> Result<(), Error> {
+            let creator = self.env().caller();
+            let mut item = self
+                .content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+
+            item.published = false;
+            self.content_items.insert((creator, item_id), &item);
+            self.unindex_content(creator, item_id);
+
+            Ok(())
+        }
+
+        /// Get one page of a creator's published catalogue (without CIDs) plus
+        /// the total number of published items
+        #[ink(message)]
+        pub fn get_content_list(
+            &self,
+            creator: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<ContentPreview>, u32) {
+            let total = self.published_count.get(creator).unwrap_or(0);
+            let items = Self::page_range(offset, limit, total)
+                .filter_map(|index| self.published_content.get((creator, index)))
+                .filter_map(|item_id| self.content_items.get((creator, item_id)))
+                .map(|item| ContentPreview {
+                    price: self.content_prices.get((creator, item.id)),
+                    ..ContentPreview::from(&item)
+                })
+                .collect();
+
+            (items, total)
+        }
+
+        /// Open a catalogue item; creators can always open their own, fans need
+        /// a live subscription on a plan of at least the item's tier, or to
+        /// have bought it (bought items stay open even once unpublished)
+        #[ink(message)]
+        pub fn get_content(&self, creator: AccountId, item_id: u32) -> Result<ContentItem, Error> {
+            let caller = self.env().caller();
+            let item = self
+                .content_items
+                .get((creator, item_id))
+                .ok_or(Error::ContentNotFound)?;
+
+            let purchased = self.purchases.contains((caller, creator, item_id));
+            if !item.published && !purchased {
+                return Err(Error::ContentNotFound);
+            }
+            if caller != creator && !purchased {
+                self.check_access(caller, creator, item.required_tier)?;
+            }
+
+            Ok(item)
+        }
+
+        /// Creator sets a one-time price on a published item (None = off sale)
+        #[ink(message)]
+        pub fn set_content_price(
+            &mut self,
+            item_id: u32,
+            price: Option<u128>,
+        ) -> Result<(), Error> {
+            let creator = self.env().caller();
+            self.content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+
+            match price {
+                Some(0) => return Err(Error::InvalidPrice),
+                Some(amount) => {
+                    self.content_prices.insert((creator, item_id), &amount);
+                }
+                None => self.content_prices.remove((creator, item_id)),
+            }
+
+            self.env().emit_event(ContentPriceUpdated {
+                creator,
+                item_id,
+                price,
+            });
+
+            Ok(())
+        }
+
+        /// Fan buys a catalogue item outright for exactly its price, gaining
+        /// permanent access; the payment is paid out straight away
+        #[ink(message, payable)]
+        pub fn purchase_content(&mut self, creator: AccountId, item_id: u32) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let fan = self.env().caller();
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            self.content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+            let price = self
+                .content_prices
+                .get((creator, item_id))
+                .ok_or(Error::NotForSale)?;
+            if self.purchases.contains((fan, creator, item_id)) {
+                return Err(Error::AlreadyPurchased);
+            }
+            if payment < price {
+                return Err(Error::InsufficientPayment);
+            }
+            if payment > price {
+                return Err(Error::PaymentMismatch);
+            }
+
+            self.purchases.insert((fan, creator, item_id), &true);
+            let amount = self.pay_creator(creator, payment)?;
+
+            self.env().emit_event(ContentPurchased {
+                fan,
+                creator,
+                item_id,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Check whether a fan has bought a catalogue item
+        #[ink(message)]
+        pub fn has_purchased(&self, fan: AccountId, creator: AccountId, item_id: u32) -> bool {
+            self.purchases.contains((fan, creator, item_id))
+        }
+
+        /// Fee manager sets the platform fee in basis points (at most MAX_PLATFORM_FEE_BPS)
+        #[ink(message)]
+        pub fn set_platform_fee(&mut self, fee_bps: u16) -> Result<(), Error> {
+            self.require_role(Role::FeeManager)?;
+            if fee_bps > MAX_PLATFORM_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+
+            let previous_fee_bps = self.get_platform_fee();
+            self.platform_fee_bps.set(&fee_bps);
+
+            self.env().emit_event(PlatformFeeUpdated {
+                previous_fee_bps,
+                fee_bps,
+            });
+
+            Ok(())
+        }
+
+        /// Owner changes where withdrawn platform fees are sent
+        #[ink(message)]
+        pub fn set_treasury(&mut self, treasury: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.treasury.set(&treasury);
+
+            self.env().emit_event(TreasuryUpdated { treasury });
+
+            Ok(())
+        }
+
+        /// Fee manager sends all accumulated platform fees to the treasury
+        #[ink(message)]
+        pub fn withdraw_platform_fees(&mut self) -> Result<u128, Error> {
+            self.require_role(Role::FeeManager)?;
+
+            let amount = self.get_platform_balance();
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let treasury = self.get_treasury();
+            self.platform_balance.set(&0);
+            if self.env().transfer(treasury, amount.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(PlatformFeesWithdrawn { treasury, amount });
+
+            Ok(amount)
+        }
+
+        /// Get the current platform fee in basis points
+        #[ink(message)]
+        pub fn get_platform_fee(&self) -> u16 {
+            self.platform_fee_bps.get().unwrap_or(0)
+        }
+
+        /// Get platform fees collected but not yet withdrawn
+        #[ink(message)]
+        pub fn get_platform_balance(&self) -> u128 {
+            self.platform_balance.get().unwrap_or(0)
+        }
+
+        /// Get the address platform fees are withdrawn to (the owner until set)
+        #[ink(message)]
+        pub fn get_treasury(&self) -> AccountId {
+            self.treasury.get().unwrap_or_else(|| self.get_owner())
+        }
+
+        /// Owner nominates a new owner, who must call accept_ownership()
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.pending_owner.set(&Some(new_owner));
+
+            self.env().emit_event(OwnershipTransferStarted {
+                owner: self.get_owner(),
+                pending_owner: new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Pending owner accepts ownership, completing the transfer
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if self.get_pending_owner() != Some(caller) {
+                return Err(Error::NotPendingOwner);
+            }
+
+            let previous_owner = self.get_owner();
+            self.owner.set(&caller);
+            self.pending_owner.set(&None);
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Grant a role (owner: any role; admins: Moderator and FeeManager)
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.ensure_can_manage(caller, role)?;
+
+            self.roles.insert((account, role), &true);
+
+            self.env().emit_event(RoleGranted {
+                account,
+                role,
+                granted_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke a role (same permissions as grant_role)
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: Role, account: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.ensure_can_manage(caller, role)?;
+
+            self.roles.remove((account, role));
+
+            self.env().emit_event(RoleRevoked {
+                account,
+                role,
+                revoked_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Check whether an account holds a role (the owner holds them all)
+        #[ink(message)]
+        pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+            account == self.get_owner() || self.roles.contains((account, role))
+        }
+
+        /// Admin pauses subscribing, top-ups and claims in an emergency
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            self.require_role(Role::Admin)?;
+            self.paused.set(&true);
+
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Admin lifts the pause
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Error> {
+            self.require_role(Role::Admin)?;
+            self.paused.set(&false);
+
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Check whether the contract is paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused.get().unwrap_or(false)
+        }
+
+        /// Get the contract owner
+        /// A contract upgraded from version 1 (which had no owner) is owned by
+        /// its own account, i.e. by governance, until ownership is transferred
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner.get().unwrap_or_else(|| self.env().account_id())
+        }
+
+        /// Get the account nominated to become owner, if any
+        #[ink(message)]
+        pub fn get_pending_owner(&self) -> Option<AccountId> {
+            self.pending_owner.get().flatten()
+        }
+
+        /// Owner replaces the contract code, keeping storage and balance
+        /// Upgrade flow: pause → upgrade → migrate_* → complete_migration → unpause
+        /// (version 1 has no upgrade(); governance swaps its code and migrates)
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: Hash) -> Result<(), Error> {
+            self.ensure_owner()?;
+
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| Error::UpgradeFailed)?;
+
+            self.env().emit_event(Upgraded { code_hash });
+
+            Ok(())
+        }
+
+        /// Get the layout version of the records in storage
+        #[ink(message)]
+        pub fn get_storage_version(&self) -> u32 {
+            self.storage_version.get().unwrap_or(1)
+        }
+
+        /// Owner rewrites version 1 creator profiles in the current layout and
+        /// lists them at ids 0..creator_count (version 1 counted but kept no list)
+        /// Returns how many were rewritten; current or unknown accounts are skipped
+        #[ink(message)]
+        pub fn migrate_creators(&mut self, creators: Vec<AccountId>) -> Result<u32, Error> {
+            self.ensure_owner()?;
+
+            let mut next_id = self.migrated_creator_ids.get().unwrap_or(0);
+            let mut migrated: u32 = 0;
+            for creator in creators {
+                if !matches!(self.creators.try_get(creator), Some(Err(_))) {
+                    continue;
+                }
+                let key = (self.creators.key(), creator);
+                let Ok(Some(legacy)) =
+                    ink::env::get_contract_storage::<_, CreatorProfileV1>(&key)
+                else {
+                    continue;
+                };
+
+                self.creators.insert(creator, &CreatorProfile::from(legacy));
+                self.creator_ids.insert(next_id, &creator);
+                next_id += 1;
+                migrated += 1;
+            }
+
+            self.migrated_creator_ids.set(&next_id);
+            Ok(migrated)
+        }
+
+        /// Owner rewrites version 1 subscriptions in the current layout, on a
+        /// retired "Legacy" plan per rate (migrate the creator first)
+        /// Returns how many were rewritten; current or unknown pairs are skipped
+        /// and zeroed (cancelled) version 1 records are deleted
+        #[ink(message)]
+        pub fn migrate_subscriptions(
+            &mut self,
+            pairs: Vec<(AccountId, AccountId)>,
+        ) -> Result<u32, Error> {
+            self.ensure_owner()?;
+
+            let mut migrated: u32 = 0;
+            for (fan, creator) in pairs {
+                if !matches!(self.subscriptions.try_get((fan, creator)), Some(Err(_))) {
+                    continue;
+                }
+                let key = (self.subscriptions.key(), (fan, creator));
+                let Ok(Some(legacy)) = ink::env::get_contract_storage::<_, SubscriptionV1>(&key)
+                else {
+                    continue;
+                };
+
+                if legacy.total_deposited == 0 || legacy.rate_per_second == 0 {
+                    ink::env::clear_contract_storage(&key);
+                    continue;
+                }
+
+                let plan_id = self.legacy_plan_id(creator, legacy.price_per_period()?)?;
+                let subscription = legacy.into_current(plan_id)?;
+                self.save_subscription((fan, creator), &subscription)?;
+                self.index_subscription(fan, creator);
+                migrated += 1;
+            }
+
+            Ok(migrated)
+        }
+
+        /// Owner marks the migration as finished, recording STORAGE_VERSION
+        #[ink(message)]
+        pub fn complete_migration(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+            let from_version = self.get_storage_version();
+            if from_version >= STORAGE_VERSION {
+                return Err(Error::AlreadyMigrated);
+            }
+
+            self.storage_version.set(&STORAGE_VERSION);
+
+            self.env().emit_event(StorageMigrated {
+                from_version,
+                to_version: STORAGE_VERSION,
+            });
+
+            Ok(())
+        }
+
+        /// Get one page of registered creators (in registration order) plus the
+        /// total number of creators; `limit` is clamped to MAX_PAGE_SIZE
+        #[ink(message)]
+        pub fn get_creator_list(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<(AccountId, CreatorProfile)>, u32) {
+            let total = self.creator_count;
+            let creators = Self::page_range(offset, limit, total)
+                .filter_map(|id| self.creator_ids.get(id))
+                .filter_map(|address| {
+                    self.creators
+                        .get(address)
+                        .map(|profile| (address, profile))
+                })
+                .collect();
+
+            (creators, total)
+        }
+
+        /// Get one page of a creator's current subscribers plus the total count
+        /// (order is not chronological: cancellations swap the last fan in)
+        #[ink(message)]
+        pub fn get_subscribers(
+            &self,
+            creator: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<(AccountId, Subscription)>, u32) {
+            let total = self.subscriber_count.get(creator).unwrap_or(0);
+            let subscribers = Self::page_range(offset, limit, total)
+                .filter_map(|index| self.subscribers.get((creator, index)))
+                .filter_map(|fan| {
+                    self.subscriptions
+                        .get((fan, creator))
+                        .map(|subscription| (fan, subscription))
+                })
+                .collect();
+
+            (subscribers, total)
+        }
+
+        /// Get one page of the creators a fan is subscribed to plus the total count
+        #[ink(message)]
+        pub fn get_fan_subscriptions(
+            &self,
+            fan: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<(AccountId, Subscription)>, u32) {
+            let total = self.fan_subscription_count.get(fan).unwrap_or(0);
+            let subscriptions = Self::page_range(offset, limit, total)
+                .filter_map(|index| self.fan_subscriptions.get((fan, index)))
+                .filter_map(|creator| {
+                    self.subscriptions
+                        .get((fan, creator))
+                        .map(|subscription| (creator, subscription))
+                })
+                .collect();
+
+            (subscriptions, total)
+        }
+
+        /// Get a creator's dashboard numbers in one call
+        #[ink(message)]
+        pub fn get_creator_stats(&self, creator: AccountId) -> Result<CreatorStats, Error> {
+            let profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            Ok(CreatorStats {
+                total_earned: profile.total_earned,
+                subscriber_count: self.subscriber_count.get(creator).unwrap_or(0),
+                published_content: self.published_count.get(creator).unwrap_or(0),
+                tip_count: self.tip_count.get(creator).unwrap_or(0),
+                total_tips: self.total_tips.get(creator).unwrap_or(0),
+            })
+        }
+
+        /// Index range for one page of a list with `total` entries
+        fn page_range(offset: u32, limit: u32, total: u32) -> core::ops::Range<u32> {
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(total);
+            offset.min(end)..end
+        }
+
+        /// Appends a subscription to the creator's and the fan's lists
+        fn index_subscription(&mut self, fan: AccountId, creator: AccountId) {
+            let creator_pos = self.subscriber_count.get(creator).unwrap_or(0);
+            self.subscribers.insert((creator, creator_pos), &fan);
+            self.subscriber_count.insert(creator, &(creator_pos + 1));
+
+            let fan_pos = self.fan_subscription_count.get(fan).unwrap_or(0);
+            self.fan_subscriptions.insert((fan, fan_pos), &creator);
+            self.fan_subscription_count.insert(fan, &(fan_pos + 1));
+
+            self.index_positions
+                .insert((fan, creator), &(creator_pos, fan_pos));
+        }
+
+        /// Swap-removes a subscription from the creator's and the fan's lists
+        fn unindex_subscription(&mut self, fan: AccountId, creator: AccountId) {
+            let Some((creator_pos, fan_pos)) = self.index_positions.take((fan, creator)) else {
+                return;
+            };
+
+            // Creator's subscriber list
+            let last = self
+                .subscriber_count
+                .get(creator)
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if creator_pos != last {
+                if let Some(moved_fan) = self.subscribers.get((creator, last)) {
+                    self.subscribers.insert((creator, creator_pos), &moved_fan);
+                    if let Some((_, moved_fan_pos)) =
+                        self.index_positions.get((moved_fan, creator))
+                    {
+                        self.index_positions
+                            .insert((moved_fan, creator), &(creator_pos, moved_fan_pos));
+                    }
+                }
+            }
+            self.subscribers.remove((creator, last));
+            self.subscriber_count.insert(creator, &last);
+
+            // Fan's subscription list
+            let last = self
+                .fan_subscription_count
+                .get(fan)
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if fan_pos != last {
+                if let Some(moved_creator) = self.fan_subscriptions.get((fan, last)) {
+                    self.fan_subscriptions.insert((fan, fan_pos), &moved_creator);
+                    if let Some((moved_creator_pos, _)) =
+                        self.index_positions.get((fan, moved_creator))
+                    {
+                        self.index_positions
+                            .insert((fan, moved_creator), &(moved_creator_pos, fan_pos));
+                    }
+                }
+            }
+            self.fan_subscriptions.remove((fan, last));
+            self.fan_subscription_count.insert(fan, &last);
+        }
+
+        /// Checks that `fan` has a live, still-funded subscription to `creator`
+        /// on a plan of at least `required_tier`
+        fn check_access(
+            &self,
+            fan: AccountId,
+            creator: AccountId,
+            required_tier: u32,
+        ) -> Result<(), Error> {
+            let Some(subscription) = self.subscriptions.get((fan, creator)) else {
+                // Archived subscriptions mean the fan had access once
+                if self.history_count.get((fan, creator)).unwrap_or(0) > 0 {
+                    return Err(Error::SubscriptionExpired);
+                }
+                return Err(Error::SubscriptionRequired);
+            };
+
+            if subscription.paid_until() <= self.env().block_timestamp() {
+                return Err(Error::SubscriptionExpired);
+            }
+
+            let plan = self
+                .plans
+                .get((creator, subscription.plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            if plan.tier < required_tier {
+                return Err(Error::TierTooLow);
+            }
+
+            Ok(())
+        }
+
+        /// Removes an item from a creator's published list (swap-remove)
+        fn unindex_content(&mut self, creator: AccountId, item_id: u32) {
+            let Some(position) = self.content_positions.take((creator, item_id)) else {
+                return;
+            };
+
+            let last = self
+                .published_count
+                .get(creator)
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if position != last {
+                if let Some(moved_id) = self.published_content.get((creator, last)) {
+                    self.published_content.insert((creator, position), &moved_id);
+                    self.content_positions.insert((creator, moved_id), &position);
+                }
+            }
+            self.published_content.remove((creator, last));
+            self.published_count.insert(creator, &last);
+        }
+
+        /// Stores a subscription after checking its ledger still balances
+        fn save_subscription(
+            &mut self,
+            subscription_key: (AccountId, AccountId),
+            subscription: &Subscription,
+        ) -> Result<(), Error> {
+            subscription.check_ledger()?;
+            self.subscriptions.insert(subscription_key, subscription);
+            Ok(())
+        }
+
+        /// Sends claimed earnings to a creator and updates their running total
+        fn pay_creator(&mut self, creator: AccountId, amount: u128) -> Result<u128, Error> {
+            // Platform fee rounds down in the creator's favour
+            let fee = amount
+                .checked_mul(u128::from(self.get_platform_fee()))
+                .ok_or(Error::Overflow)?
+                / BPS_DENOMINATOR;
+            let payout = amount - fee;
+            let platform_balance = self
+                .get_platform_balance()
+                .checked_add(fee)
+                .ok_or(Error::Overflow)?;
+            self.platform_balance.set(&platform_balance);
+
+            // Update creator's total earnings for display purposes
+            if let Some(mut profile) = self.creators.get(creator) {
+                profile.total_earned = profile.total_earned.saturating_add(payout);
+                self.creators.insert(creator, &profile);
+            }
+
+            // Transfer the claimed DOT to the creator (or their collaborators)
+            self.distribute(creator, payout)?;
+
+            Ok(payout)
+        }
+
+        /// Sends a payout to the creator's split payees; the first payee also
+        /// gets the rounding remainder
+        fn distribute(&mut self, creator: AccountId, payout: u128) -> Result<(), Error> {
+            if payout == 0 {
+                return Ok(());
+            }
+
+            let Some(payees) = self.revenue_splits.get(creator) else {
+                return self.send_or_credit(creator, payout);
+            };
+
+            let amounts = Self::split_amounts(payout, &payees)?;
+            for (share, amount) in payees.iter().zip(amounts) {
+                self.send_or_credit(share.payee, amount)?;
+            }
+
+            Ok(())
+        }
+
+        /// Transfers `amount` to `payee`, or owes it in `payee_balances` if the
+        /// transfer fails, so one payee can't block the caller's operation
+        fn send_or_credit(&mut self, payee: AccountId, amount: u128) -> Result<(), Error> {
+            if amount == 0 || self.env().transfer(payee, amount.into()).is_ok() {
+                return Ok(());
+            }
+
+            let owed = self.payee_balances.get(payee).unwrap_or(0);
+            let owed = owed.checked_add(amount).ok_or(Error::Overflow)?;
+            self.payee_balances.insert(payee, &owed);
+            Ok(())
+        }
+
+        /// Divides `payout` by share, remainder to the first payee
+        fn split_amounts(payout: u128, payees: &[RevenueShare]) -> Result<Vec<u128>, Error> {
+            let mut amounts = Vec::with_capacity(payees.len());
+            let mut allocated: u128 = 0;
+            for share in payees {
+                let amount = payout
+                    .checked_mul(u128::from(share.share_bps))
+                    .ok_or(Error::Overflow)?
+                    / BPS_DENOMINATOR;
+                allocated += amount;
+                amounts.push(amount);
+            }
+            if let Some(first) = amounts.first_mut() {
+                *first += payout - allocated;
+            }
+            Ok(amounts)
+        }
+
+        /// Id of the retired plan holding `creator`'s migrated subscriptions
+        /// paying `price` per 30 days, created inactive on first use
+        fn legacy_plan_id(&mut self, creator: AccountId, price: u128) -> Result<u32, Error> {
+            if let Some(plan_id) = self.legacy_plans.get((creator, price)) {
+                return Ok(plan_id);
+            }
+
+            let mut profile = match self.creators.try_get(creator) {
+                Some(Ok(profile)) => profile,
+                _ => return Err(Error::CreatorNotFound),
+            };
+
+            let plan_id = profile.plan_count;
+            self.plans.insert(
+                (creator, plan_id),
+                &SubscriptionPlan {
+                    name: String::from("Legacy"),
+                    price,
+                    period: BillingPeriod::Days(30),
+                    perks: Vec::new(),
+                    active: false,
+                    tier: 0,
+                    min_commitment_periods: 0,
+                },
+            );
+            profile.plan_count += 1;
+            self.creators.insert(creator, &profile);
+            self.legacy_plans.insert((creator, price), &plan_id);
+
+            Ok(plan_id)
+        }
+
+        /// Loads an open pool run by the caller
+        fn sponsor_pool(&self, pool_id: u32) -> Result<SponsorshipPool, Error> {
+            let pool = self.pools.get(pool_id).ok_or(Error::PoolNotFound)?;
+            if pool.sponsor != self.env().caller() {
+                return Err(Error::NotSponsor);
+            }
+            if !pool.active {
+                return Err(Error::PoolClosed);
+            }
+            Ok(pool)
+        }
+
+        /// Keccak-256 hash of a coupon or invite code
+        fn hash_code(code: &str) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Keccak256>(code.as_bytes(), &mut output);
+            output
+        }
+
+        /// Price of one period after a coupon; fails if the coupon is unknown,
+        /// expired, used up or would make the plan free
+        fn coupon_price(
+            &self,
+            creator: AccountId,
+            code_hash: [u8; 32],
+            price: u128,
+        ) -> Result<u128, Error> {
+            let coupon = self
+                .coupons
+                .get((creator, code_hash))
+                .ok_or(Error::CouponNotFound)?;
+
+            if coupon
+                .expires_at
+                .is_some_and(|expires_at| self.env().block_timestamp() >= expires_at)
+            {
+                return Err(Error::CouponExpired);
+            }
+            if coupon.max_redemptions > 0 && coupon.redemptions >= coupon.max_redemptions {
+                return Err(Error::CouponExhausted);
+            }
+
+            coupon.discount.apply(price).ok_or(Error::InvalidCoupon)
+        }
+
+        /// Counts one use of a coupon and returns the new redemption count
+        fn redeem_coupon(
+            &mut self,
+            creator: AccountId,
+            code_hash: [u8; 32],
+        ) -> Result<u32, Error> {
+            let mut coupon = self
+                .coupons
+                .get((creator, code_hash))
+                .ok_or(Error::CouponNotFound)?;
+            coupon.redemptions = coupon.redemptions.checked_add(1).ok_or(Error::Overflow)?;
+            self.coupons.insert((creator, code_hash), &coupon);
+            Ok(coupon.redemptions)
+        }
+
+        /// Fails with NotOwner unless the caller is the contract owner
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.get_owner() {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        /// Fails with MissingRole unless the caller holds `role` (or is the owner)
+        fn require_role(&self, role: Role) -> Result<(), Error> {
+            if !self.has_role(role, self.env().caller()) {
+                return Err(Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// Fails with ContractPaused while the emergency pause is on
+        fn ensure_not_paused(&self) -> Result<(), Error> {
+            if self.is_paused() {
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
+
+        /// Fails with MissingRole unless `caller` may grant or revoke `role`
+        fn ensure_can_manage(&self, caller: AccountId, role: Role) -> Result<(), Error> {
+            let allowed = match role {
+                Role::Admin => caller == self.get_owner(),
+                Role::Moderator | Role::FeeManager => self.has_role(Role::Admin, caller),
+            };
+            if !allowed {
+                return Err(Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// Settles a range of the creator's subscriber list and pays the
+        /// aggregate in one transfer; returns (claimed, next offset)
+        fn claim_range(
+            &mut self,
+            creator: AccountId,
+            range: core::ops::Range<u32>,
+        ) -> Result<(u128, u32), Error> {
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+
+            let end = range.end;
+            let now = self.env().block_timestamp();
+
+            let mut claimed: u128 = 0;
+            let mut settled: u32 = 0;
+            for index in range {
+                let Some(fan) = self.subscribers.get((creator, index)) else {
+                    continue;
+                };
+                let subscription_key = (fan, creator);
+                let Some(mut subscription) = self.subscriptions.get(subscription_key) else {
+                    continue;
+                };
+
+                subscription.settle(now);
+                let claimable_amount = subscription.claimable();
+                if claimable_amount == 0 {
+                    continue;
+                }
+                subscription.total_claimed += claimable_amount;
+                self.save_subscription(subscription_key, &subscription)?;
+
+                claimed = claimed
+                    .checked_add(claimable_amount)
+                    .ok_or(Error::Overflow)?;
+                settled += 1;
+            }
+
+            if claimed == 0 {
+                return Ok((0, end));
+            }
+
+            let paid = self.pay_creator(creator, claimed)?;
+            self.env().emit_event(BatchEarningsClaimed {
+                creator,
+                amount: paid,
+                subscriptions: settled,
+            });
+
+            Ok((paid, end))
+        }
+
+        /// Opens a subscription for `fan` funded with `payment_u128` by `payer`
+        /// (None = the fan), shared by subscribe, gift_subscription and
+        /// claim_sponsorship; returns the deposit
+        fn open_subscription(
+            &mut self,
+            fan: AccountId,
+            creator: AccountId,
+            plan_id: u32,
+            coupon: Option<String>,
+            payment_u128: u128,
+            payer: Option<AccountId>,
+        ) -> Result<u128, Error> {
+            // Verify the creator exists
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+
+            // Look up the plan the fan picked
+            let plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            if !plan.active {
+                return Err(Error::PlanInactive);
+            }
+
+            // Get current timestamp for subscription start
+            let now = self.env().block_timestamp();
+
+            // A funded subscription must be topped up; a depleted one is archived
+            let subscription_key = (fan, creator);
+            if let Some(existing) = self.subscriptions.get(subscription_key) {
+                if existing.paid_until() > now {
+                    return Err(Error::SubscriptionAlreadyExists);
+                }
+                self.close_subscription(fan, creator, SubscriptionEnd::Depleted)?;
+            }
+
+            // A coupon lowers the price of every period of this subscription
+            let code_hash = coupon.as_deref().map(Self::hash_code);
+            let price = match code_hash {
+                Some(code_hash) => self.coupon_price(creator, code_hash, plan.price)?,
+                None => plan.price,
+            };
+
+            // Verify the payment covers the commitment and whole billing periods
+            let committed = price
+                .checked_mul(u128::from(plan.min_commitment_periods))
+                .ok_or(Error::Overflow)?;
+            if payment_u128 < price.max(committed) {
+                return Err(Error::InsufficientPayment);
+            }
+            if payment_u128 % price != 0 {
+                return Err(Error::PaymentMismatch);
+            }
+
+            // First subscription to a creator gets the plan's free trial:
+            // streaming starts when it ends
+            let trial_days = self.plan_trials.get((creator, plan_id)).unwrap_or(0);
+            let mut trial_ends_at = None;
+            if trial_days > 0 && !self.trials_used.contains(subscription_key) {
+                let trial_ms = BillingPeriod::Days(trial_days).duration_ms();
+                trial_ends_at = Some(now.saturating_add(trial_ms));
+                self.trials_used.insert(subscription_key, &true);
+            }
+
+            // Create subscription record, streaming price per billing period
+            let subscription = Subscription {
+                plan_id,
+                total_deposited: payment_u128,
+                total_vested: 0,
+                total_claimed: 0,
+                total_refunded: 0,
+                price_per_period: price,
+                billing_period: plan.period,
+                committed,
+                vesting_remainder: 0,
+                settled_until: trial_ends_at.unwrap_or(now),
+                start_time: now,
+                payer,
+            };
+
+            // Store subscription in mapping and list it for both parties
+            self.save_subscription(subscription_key, &subscription)?;
+            self.index_subscription(fan, creator);
+
+            // Emit event for frontend notification
+            self.env().emit_event(SubscriptionCreated {
+                fan,
+                creator,
+                plan_id,
+                total_deposited: payment_u128,
+                trial_ends_at,
+            });
+
+            if let Some(code_hash) = code_hash {
+                let redemptions = self.redeem_coupon(creator, code_hash)?;
+                self.env().emit_event(CouponRedeemed {
+                    fan,
+                    creator,
+                    code_hash,
+                    plan_id,
+                    price_per_period: price,
+                    redemptions,
+                });
+            }
+
+            Ok(payment_u128)
+        }
+
+        /// Settles the live subscription, pays the creator, refunds the fan,
+        /// archives a summary and removes the record. Returns the refund.
+        fn close_subscription(
+            &mut self,
+            fan: AccountId,
+            creator: AccountId,
+            reason: SubscriptionEnd,
+        ) -> Result<u128, Error> {
+            let subscription_key = (fan, creator);
+            let mut subscription = self
+                .subscriptions
+                .take(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)?;
+
+            let now = self.env().block_timestamp();
+            subscription.settle(now);
+
+            // Closing early still pays out the fan's minimum commitment,
+            // unless the fan is still in their free trial
+            if subscription.settled_until <= now {
+                let shortfall = subscription
+                    .committed
+                    .saturating_sub(subscription.total_vested)
+                    .min(subscription.unvested());
+                subscription.total_vested += shortfall;
+            }
+
+            // Creator keeps everything that vested, fan gets back the rest
+            let earned = subscription.claimable();
+            let refund_amount = subscription.unvested();
+            subscription.total_claimed += earned;
+            subscription.total_refunded += refund_amount;
+            subscription.check_ledger()?;
+
+            self.unindex_subscription(fan, creator);
+
+            let index = self.history_count.get(subscription_key).unwrap_or(0);
+            let archived = ArchivedSubscription {
+                plan_id: subscription.plan_id,
+                start_time: subscription.start_time,
+                ended_at: now,
+                total_deposited: subscription.total_deposited,
+                total_claimed: subscription.total_claimed,
+                refunded: subscription.total_refunded,
+                reason,
+            };
+            self.subscription_history
+                .insert((fan, creator, index), &archived);
+            self.history_count
+                .insert(subscription_key, &index.saturating_add(1));
+
+            if earned > 0 && self.is_paused() {
+                // No payouts while paused; the creator claims after unpause
+                let held = self.held_earnings.get(creator).unwrap_or(0);
+                let held = held.checked_add(earned).ok_or(Error::Overflow)?;
+                self.held_earnings.insert(creator, &held);
+            } else if earned > 0 {
+                let paid = self.pay_creator(creator, earned)?;
+                self.env().emit_event(EarningsClaimed {
+                    creator,
+                    fan,
+                    amount: paid,
+                });
+            }
+
+            // A sponsored seat refunds into its pool, or the sponsor once it's closed
+            let mut refund_to = subscription.payer.unwrap_or(fan);
+            if let Some(pool_id) = self.sponsored_by.take(subscription_key) {
+                if let Some(mut pool) = self.pools.get(pool_id) {
+                    if pool.active {
+                        pool.balance = pool
+                            .balance
+                            .checked_add(refund_amount)
+                            .ok_or(Error::Overflow)?;
+                        self.pools.insert(pool_id, &pool);
+                        return Ok(refund_amount);
+                    }
+                    refund_to = pool.sponsor;
+                }
+            }
+
+            // Transfer refund to fan (or the payer of a gift) if there's anything to refund
+            if refund_amount > 0 && self.env().transfer(refund_to, refund_amount.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            Ok(refund_amount)
         }
     }
 }
@@ -107,14 +107,11 @@ pub enum Error {
 // Events are like notifications that the frontend can listen to
 // They're emitted when important things happen in the contract
 
-// TODO: Fix H160 compatibility with ink! events
-// Temporarily commented out to resolve compilation issues
-
-/*
 /// Emitted when a new creator joins the platform
 #[ink::event]
 pub struct CreatorRegistered {
     /// Address of the new creator (indexed for efficient searching)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// Display name of the creator
@@ -125,9 +122,11 @@ pub struct CreatorRegistered {
 #[ink::event]
 pub struct SubscriptionCreated {
     /// Fan's wallet address (indexed)
+    #[ink(topic)]
     pub fan: ink::primitives::H160,
 
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// Monthly rate in DOT (for display)
@@ -141,9 +140,11 @@ pub struct SubscriptionCreated {
 #[ink::event]
 pub struct EarningsClaimed {
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// Fan who was paying (indexed)
+    #[ink(topic)]
     pub fan: ink::primitives::H160,
 
     /// Amount of DOT claimed
@@ -154,9 +155,11 @@ pub struct EarningsClaimed {
 #[ink::event]
 pub struct SubscriptionCancelled {
     /// Fan's wallet address (indexed)
+    #[ink(topic)]
     pub fan: ink::primitives::H160,
 
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// Amount of DOT refunded to fan
@@ -167,12 +170,12 @@ pub struct SubscriptionCancelled {
 #[ink::event]
 pub struct ContentAdded {
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// IPFS hash of the content
     pub content_hash: String,
 }
-*/
 
 // 🏗️ MAIN CONTRACT STRUCTURE
 // This is the "database" that lives on the blockchain
@@ -263,11 +266,10 @@ mod creator_treasury_pop {
 
             // Emit event to notify frontend about new creator
             // The frontend can listen for this and update the UI
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(CreatorRegistered {
-            //     creator: caller,
-            //     name,
-            // });
+            self.env().emit_event(CreatorRegistered {
+                creator: caller,
+                name,
+            });
 
             Ok(())
         }
@@ -370,13 +372,12 @@ mod creator_treasury_pop {
             // Store subscription in mapping
             self.subscriptions.insert(subscription_key, &subscription);
 
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(SubscriptionCreated {
-            //     fan,
-            //     creator,
-            //     monthly_rate,
-            //     total_deposited: payment_u128,
-            // });
+            self.env().emit_event(SubscriptionCreated {
+                fan,
+                creator,
+                monthly_rate,
+                total_deposited: payment_u128,
+            });
 
             Ok(())
         }
@@ -499,12 +500,11 @@ mod creator_treasury_pop {
 
             // Emit event for frontend notification
             // The frontend can listen for this to update the UI in real-time
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(EarningsClaimed {
-            //     creator,
-            //     fan,
-            //     amount: claimable_amount,
-            // });
+            self.env().emit_event(EarningsClaimed {
+                creator,
+                fan,
+                amount: claimable_amount,
+            });
 
             Ok(claimable_amount)
         }
@@ -586,12 +586,11 @@ mod creator_treasury_pop {
 
             // Emit event for frontend notification
             // This allows the UI to show cancellation confirmation
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(SubscriptionCancelled {
-            //     fan,
-            //     creator,
-            //     refund_amount,
-            // });
+            self.env().emit_event(SubscriptionCancelled {
+                fan,
+                creator,
+                refund_amount,
+            });
 
             Ok(refund_amount)
         }
@@ -645,11 +644,10 @@ mod creator_treasury_pop {
 
             // Emit event for frontend notification
             // This allows the UI to show that new content is available
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(ContentAdded {
-            //     creator,
-            //     content_hash,
-            // });
+            self.env().emit_event(ContentAdded {
+                creator,
+                content_hash,
+            });
 
             Ok(())
         }
@@ -20,7 +20,7 @@
 // ink! is Rust-based smart contract language for Polkadot
 use ink::prelude::string::String;
 use ink::prelude::vec::Vec;
-use ink::storage::Mapping;
+use ink::storage::{Lazy, Mapping};
 
 // 📊 DATA STRUCTURES
 // These define the shape of data we store on the blockchain
@@ -47,6 +47,102 @@ pub struct CreatorProfile {
     /// Timestamp when creator registered (Unix timestamp in milliseconds)
     /// Used to show "Member since" information
     pub created_at: u64,
+
+    /// Number of subscription plans this creator has defined
+    /// Plan ids run from 0 to plan_count - 1
+    pub plan_count: u32,
+
+    /// Number of catalogue items this creator has ever added
+    /// Item ids run from 0 to content_count - 1 (unpublished ids are not reused)
+    pub content_count: u32,
+}
+
+/// BillingPeriod is how often a plan charges its price
+/// Every vesting calculation streams one plan price per period
+///
+/// Fixed periods use a set number of days. Calendar periods use the average
+/// Gregorian month/year (365.2425 days per year), so twelve calendar months
+/// stream exactly one calendar year's worth of payments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum BillingPeriod {
+    /// Every 7 days
+    Weekly,
+
+    /// Every 30 days
+    Monthly,
+
+    /// Every 90 days
+    Quarterly,
+
+    /// Every 365 days
+    Yearly,
+
+    /// Every average calendar month (30.436875 days)
+    CalendarMonth,
+
+    /// Every average calendar year (365.2425 days)
+    CalendarYear,
+
+    /// Custom period of the given number of days
+    Days(u32),
+}
+
+impl BillingPeriod {
+    const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+    /// Length of one period in milliseconds
+    /// Returns 0 for Days(0), which plans reject as invalid
+    pub fn duration_ms(&self) -> u64 {
+        match self {
+            BillingPeriod::Weekly => 7 * Self::DAY_MS,
+            BillingPeriod::Monthly => 30 * Self::DAY_MS,
+            BillingPeriod::Quarterly => 90 * Self::DAY_MS,
+            BillingPeriod::Yearly => 365 * Self::DAY_MS,
+            // 365.2425 days / 12 = 2,629,746 seconds
+            BillingPeriod::CalendarMonth => 2_629_746_000,
+            // 365.2425 days = 31,556,952 seconds
+            BillingPeriod::CalendarYear => 31_556_952_000,
+            BillingPeriod::Days(days) => u64::from(*days) * Self::DAY_MS,
+        }
+    }
+}
+
+/// SubscriptionPlan is a named tier that a creator offers to fans
+/// Creators price their own offering instead of fans choosing a rate
+/// Example: "Supporter" at 5 DOT every 30 days, "Patron" at 50 DOT
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SubscriptionPlan {
+    /// Display name of the tier (e.g., "Supporter", "Pro", "Patron")
+    pub name: String,
+
+    /// Price of one billing period in Planck units
+    /// Fans must deposit a whole number of periods when subscribing
+    pub price: u128,
+
+    /// How often the price is charged (e.g., BillingPeriod::Monthly)
+    pub period: BillingPeriod,
+
+    /// Short descriptions of what subscribers on this plan get
+    /// Example: ["Early access", "Monthly Q&A"]
+    pub perks: Vec<String>,
+
+    /// Whether new fans can still subscribe to this plan
+    /// Existing subscriptions keep streaming when a plan is retired
+    pub active: bool,
+
+    /// Access level of the plan - content requiring tier N is open to
+    /// subscribers on any plan with tier >= N
+    /// New plans start at tier 0 (set_plan_tier raises it)
+    pub tier: u32,
+
+    /// Number of billing periods a fan commits to when subscribing
+    /// Fans must deposit at least this many periods, and cancelling early
+    /// still pays the creator for all of them (0 = fully refundable)
+    pub min_commitment_periods: u32,
 }
 
 /// Subscription represents a fan's ongoing payment to a creator
@@ -55,22 +151,538 @@ pub struct CreatorProfile {
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 pub struct Subscription {
-    /// Total DOT deposited by fan for this subscription
+    /// Id of the creator's plan this subscription was opened on
+    pub plan_id: u32,
+
+    /// Total DOT deposited by fan for this subscription (initial deposit + top-ups)
     /// This is the "prepaid balance" that streams to the creator
+    /// Never decreases - claims and refunds are tracked separately below
     pub total_deposited: u128,
 
-    /// How much DOT flows to creator per second
-    /// Calculated as: monthly_rate / (30 * 24 * 60 * 60)
-    /// Example: 5 DOT/month = 1,929 Planck/second
-    pub rate_per_second: u128,
+    /// Total DOT that has vested to the creator up to settled_until
+    /// Includes both claimed and not-yet-claimed earnings
+    pub total_vested: u128,
 
-    /// Last time creator claimed earnings (Unix timestamp)
-    /// Used to calculate how much has vested since last claim
-    pub last_claim_time: u64,
+    /// Total DOT the creator has actually been paid from this subscription
+    pub total_claimed: u128,
+
+    /// Total DOT returned to the fan from this subscription
+    pub total_refunded: u128,
+
+    /// Streaming rate numerator: DOT that flows to the creator per billing period
+    /// The rate is kept as the exact fraction price_per_period / period length
+    /// instead of a rounded per-second amount, so small plans never round to zero
+    /// Example: 5 DOT every 30 days streams exactly 5 DOT over 2,592,000,000 ms
+    pub price_per_period: u128,
+
+    /// Streaming rate denominator: the billing period copied from the plan
+    /// Kept on the subscription so later plan edits don't change its rate
+    pub billing_period: BillingPeriod,
+
+    /// Amount the creator is owed even if the fan cancels right away
+    /// Fixed at subscribe time from the plan's minimum commitment
+    pub committed: u128,
+
+    /// Fraction of a Planck carried over between settlements
+    /// Stored as a numerator over the period length in milliseconds, so no dust
+    /// is lost to integer division - it vests as soon as it adds up to 1 Planck
+    pub vesting_remainder: u128,
+
+    /// Vesting has been accounted for up to this time (Unix timestamp)
+    /// Everything streamed before it is already included in total_vested
+    /// During a free trial it lies in the future: nothing vests until then
+    pub settled_until: u64,
 
     /// When subscription started (Unix timestamp)
-    /// Used to calculate total elapsed time for refunds
+    /// Used for display and subscription history
+    pub start_time: u64,
+
+    /// Account that paid for the subscription when it wasn't the fan
+    /// (a gift or a sponsored seat) - refunds go back to it, and the fan
+    /// can't top the subscription up
+    pub payer: Option<ink::primitives::H160>,
+}
+
+// 📒 SUBSCRIPTION LEDGER
+// Every subscription keeps a small ledger so that claims, top-ups and
+// cancellations all agree on how much DOT belongs to whom:
+//
+//   total_deposited = total_claimed + total_refunded + outstanding
+//   outstanding     = claimable (vested, unclaimed) + unvested
+//
+// settle() is the only place where vesting is calculated. Every message that
+// changes a subscription settles it first, then moves DOT out of the
+// claimable or unvested bucket.
+
+impl Subscription {
+    /// DOT that has vested to the creator but hasn't been claimed yet
+    pub fn claimable(&self) -> u128 {
+        self.total_vested.saturating_sub(self.total_claimed)
+    }
+
+    /// DOT that hasn't streamed yet - this is what a fan gets back on cancel
+    pub fn unvested(&self) -> u128 {
+        self.total_deposited
+            .saturating_sub(self.total_vested)
+            .saturating_sub(self.total_refunded)
+    }
+
+    /// DOT the contract still holds for this subscription (claimable + unvested)
+    pub fn outstanding(&self) -> u128 {
+        self.total_deposited
+            .saturating_sub(self.total_claimed)
+            .saturating_sub(self.total_refunded)
+    }
+
+    /// Brings total_vested up to date with `now` (Unix timestamp in milliseconds)
+    ///
+    /// **Fixed-Point Vesting**:
+    /// ```text
+    /// period_ms          = billing_period.duration_ms()
+    /// accrued            = price_per_period * elapsed_ms + vesting_remainder
+    /// total_vested      += accrued / period_ms
+    /// vesting_remainder  = accrued % period_ms
+    /// ```
+    /// Nothing is rounded away: over one full period exactly price_per_period
+    /// vests, however often the stream is settled. If the stream runs dry,
+    /// the remainder is dropped - nothing accrues until the fan tops up again.
+    pub fn settle(&mut self, now: u64) {
+        let period_ms = u128::from(self.billing_period.duration_ms());
+        if now <= self.settled_until || period_ms == 0 {
+            return;
+        }
+
+        let elapsed_ms = u128::from(now - self.settled_until);
+        let accrued = self
+            .price_per_period
+            .saturating_mul(elapsed_ms)
+            .saturating_add(self.vesting_remainder);
+        let vested_amount = accrued / period_ms;
+        let unvested = self.unvested();
+
+        if vested_amount >= unvested {
+            self.total_vested += unvested;
+            self.vesting_remainder = 0;
+        } else {
+            self.total_vested += vested_amount;
+            self.vesting_remainder = accrued % period_ms;
+        }
+        self.settled_until = now;
+    }
+
+    /// Timestamp (milliseconds) at which the unvested balance will have fully
+    /// streamed to the creator
+    /// Returns settled_until once nothing is left to stream, and u64::MAX for a
+    /// funded stream that never runs out (zero rate)
+    pub fn paid_until(&self) -> u64 {
+        let unvested = self.unvested();
+        if unvested == 0 {
+            return self.settled_until;
+        }
+        if self.price_per_period == 0 {
+            return u64::MAX;
+        }
+
+        // Smallest number of milliseconds after which settle() vests everything
+        let needed = unvested
+            .saturating_mul(u128::from(self.billing_period.duration_ms()))
+            .saturating_sub(self.vesting_remainder);
+        let millis_left = needed.div_ceil(self.price_per_period);
+        self.settled_until
+            .saturating_add(u64::try_from(millis_left).unwrap_or(u64::MAX))
+    }
+
+    /// Verifies deposited = claimed + refunded + outstanding can hold
+    /// i.e. the creator never claims more than vested, and vested plus
+    /// refunded never exceeds what the fan deposited
+    pub fn check_ledger(&self) -> Result<(), Error> {
+        let spent = self
+            .total_vested
+            .checked_add(self.total_refunded)
+            .ok_or(Error::Overflow)?;
+        if self.total_claimed > self.total_vested || spent > self.total_deposited {
+            return Err(Error::LedgerImbalance);
+        }
+        Ok(())
+    }
+}
+
+// 🗄️ LEGACY STORAGE LAYOUTS
+// Shapes of records written by earlier versions of the contract. After an
+// upgrade these stay in storage under the same keys until a migration
+// message rewrites them in the current layout.
+
+/// Number of seconds in a legacy subscription month (rates were per second
+/// of a 30 day month before billing periods existed)
+pub const LEGACY_SECONDS_PER_PERIOD: u128 = 30 * 24 * 60 * 60;
+
+/// CreatorProfile as stored by storage version 1 (before plans and catalogue)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct CreatorProfileV1 {
+    pub name: String,
+    pub content_hash: Option<String>,
+    pub total_earned: u128,
+    pub created_at: u64,
+}
+
+impl From<CreatorProfileV1> for CreatorProfile {
+    fn from(legacy: CreatorProfileV1) -> Self {
+        Self {
+            name: legacy.name,
+            content_hash: legacy.content_hash,
+            total_earned: legacy.total_earned,
+            created_at: legacy.created_at,
+            plan_count: 0,
+            content_count: 0,
+        }
+    }
+}
+
+/// Subscription as stored by storage version 1 (per-second rate, no ledger)
+/// total_deposited was the remaining balance - it shrank with every claim
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SubscriptionV1 {
+    pub total_deposited: u128,
+    pub rate_per_second: u128,
+    pub last_claim_time: u64,
+    pub start_time: u64,
+}
+
+impl SubscriptionV1 {
+    /// The per-second rate expressed per 30 day period
+    pub fn price_per_period(&self) -> Result<u128, Error> {
+        self.rate_per_second
+            .checked_mul(LEGACY_SECONDS_PER_PERIOD)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Converts to the current layout on `plan_id`
+    ///
+    /// The remaining balance becomes a fresh ledger, streaming at the same
+    /// rate expressed per 30 day period. Time since the last claim hasn't been
+    /// paid out yet, so vesting resumes from last_claim_time.
+    pub fn into_current(self, plan_id: u32) -> Result<Subscription, Error> {
+        let price_per_period = self.price_per_period()?;
+
+        Ok(Subscription {
+            plan_id,
+            total_deposited: self.total_deposited,
+            total_vested: 0,
+            total_claimed: 0,
+            total_refunded: 0,
+            price_per_period,
+            billing_period: BillingPeriod::Days(30),
+            committed: 0,
+            vesting_remainder: 0,
+            settled_until: self.last_claim_time,
+            start_time: self.start_time,
+            payer: None,
+        })
+    }
+}
+
+/// Why a subscription stopped streaming
+/// Stored with archived subscriptions so fans can see their history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum SubscriptionEnd {
+    /// Fan cancelled and was refunded the unused balance
+    Cancelled,
+
+    /// The whole deposit streamed to the creator
+    Depleted,
+}
+
+/// ArchivedSubscription is a compact record of a subscription that has ended
+/// The full Subscription is removed from storage (reclaiming its deposit) and
+/// only this summary is kept so a fan can restart without losing their history
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct ArchivedSubscription {
+    /// Plan the subscription was on
+    pub plan_id: u32,
+
+    /// When the subscription started (Unix timestamp in milliseconds)
     pub start_time: u64,
+
+    /// When the subscription was closed (Unix timestamp in milliseconds)
+    pub ended_at: u64,
+
+    /// Total DOT the fan deposited over the subscription's lifetime
+    pub total_deposited: u128,
+
+    /// Total DOT paid to the creator
+    pub total_claimed: u128,
+
+    /// Amount of DOT returned to the fan when it was closed
+    pub refunded: u128,
+
+    /// Whether the fan cancelled or the deposit ran out
+    pub reason: SubscriptionEnd,
+}
+
+/// ContentType tells the frontend how to render a catalogue item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum ContentType {
+    /// Video file or stream
+    Video,
+
+    /// Music, podcast or other audio
+    Audio,
+
+    /// Picture or gallery
+    Image,
+
+    /// Written post
+    Article,
+
+    /// Any other file fans download (e.g., PDF, project files)
+    Download,
+}
+
+/// ContentItem is one piece of exclusive content in a creator's catalogue
+/// The content itself lives on IPFS - only its CID is stored on-chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct ContentItem {
+    /// Id of the item within the creator's catalogue
+    pub id: u32,
+
+    /// IPFS content identifier (only returned to fans with access)
+    /// Example: "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u"
+    pub cid: String,
+
+    /// Display title (e.g., "Behind the scenes: episode 4")
+    pub title: String,
+
+    /// What kind of content the CID points to
+    pub content_type: ContentType,
+
+    /// When the item was added (Unix timestamp in milliseconds)
+    pub created_at: u64,
+
+    /// Minimum plan tier needed to open the item (0 = any subscriber)
+    pub required_tier: u32,
+
+    /// False once the creator unpublishes the item
+    pub published: bool,
+}
+
+/// ContentPreview is the public part of a ContentItem, without the CID
+/// Returned by catalogue listings so fans can browse before subscribing
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct ContentPreview {
+    /// Id of the item within the creator's catalogue
+    pub id: u32,
+
+    /// Display title
+    pub title: String,
+
+    /// What kind of content the item is
+    pub content_type: ContentType,
+
+    /// When the item was added (Unix timestamp in milliseconds)
+    pub created_at: u64,
+
+    /// Minimum plan tier needed to open the item
+    pub required_tier: u32,
+
+    /// One-time price to buy the item outright (None = subscribers only)
+    pub price: Option<u128>,
+}
+
+impl From<&ContentItem> for ContentPreview {
+    fn from(item: &ContentItem) -> Self {
+        ContentPreview {
+            id: item.id,
+            title: item.title.clone(),
+            content_type: item.content_type,
+            created_at: item.created_at,
+            required_tier: item.required_tier,
+            price: None,
+        }
+    }
+}
+
+/// Role is a privilege the owner hands out for platform administration
+/// The owner implicitly holds every role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Role {
+    /// Runs day-to-day operations and manages Moderator and FeeManager roles
+    Admin,
+
+    /// Reviews creators and content
+    Moderator,
+
+    /// Sets the platform fee and withdraws collected fees
+    FeeManager,
+}
+
+/// SubscriptionStatus is the on-chain answer to "is this subscription running?"
+/// Computed at query time so clients don't need to re-implement vesting math
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum SubscriptionStatus {
+    /// Deposit is still streaming to the creator
+    Active,
+
+    /// The whole deposit has streamed - the fan needs to top up or resubscribe
+    Depleted,
+
+    /// The fan cancelled and was refunded
+    Cancelled,
+
+    /// The contract is paused: the deposit keeps streaming, but it can't be
+    /// topped up or claimed until an admin unpauses (cancelling still works)
+    Paused,
+}
+
+/// SubscriptionOverview is a point-in-time summary of a fan's subscription
+/// Returned by get_subscription_status()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SubscriptionOverview {
+    /// Current state of the subscription
+    pub status: SubscriptionStatus,
+
+    /// DOT vested to the creator but not claimed yet
+    pub claimable: u128,
+
+    /// DOT still left to stream (refundable on cancel, less any unmet commitment)
+    pub remaining: u128,
+
+    /// When the deposit runs out (Unix timestamp in milliseconds)
+    /// For an ended subscription this is when it was closed
+    pub paid_until: u64,
+}
+
+/// Discount is how much a coupon takes off a plan's price
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Discount {
+    /// Percentage off every period (1 to 99)
+    Percent(u8),
+
+    /// Flat amount off every period, in Planck
+    Fixed(u128),
+}
+
+impl Discount {
+    /// Price of one period after the discount
+    /// Returns None if nothing would be left to pay
+    pub fn apply(&self, price: u128) -> Option<u128> {
+        let discounted = match *self {
+            Discount::Percent(percent) => {
+                price.checked_mul(u128::from(100 - percent.min(100)))? / 100
+            }
+            Discount::Fixed(amount) => price.saturating_sub(amount),
+        };
+        (discounted > 0).then_some(discounted)
+    }
+}
+
+/// Coupon is a creator's promotion, redeemed by passing its code to subscribe()
+/// Stored under the Keccak-256 hash of the code, so codes aren't readable
+/// from storage before fans use them
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Coupon {
+    /// What the coupon takes off the plan price
+    /// Applies to every period of the subscription, including top-ups
+    pub discount: Discount,
+
+    /// How many subscriptions can use the coupon (0 = unlimited)
+    pub max_redemptions: u32,
+
+    /// How many subscriptions have used it so far
+    pub redemptions: u32,
+
+    /// Coupon stops working at this time (Unix timestamp in milliseconds)
+    pub expires_at: Option<u64>,
+}
+
+/// SponsorshipPool is a sponsor's budget for subscribing other people
+/// Each beneficiary claims one seat: a subscription to the pool's plan paid
+/// from the pool. Unused DOT from cancelled seats flows back into the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SponsorshipPool {
+    /// Account that funds the pool and manages its beneficiaries
+    pub sponsor: ink::primitives::H160,
+
+    /// Creator whose plan the seats are for (e.g., a course)
+    pub creator: ink::primitives::H160,
+
+    /// Plan every seat subscribes to
+    pub plan_id: u32,
+
+    /// Billing periods each seat pays for
+    pub periods_per_seat: u32,
+
+    /// DOT left in the pool for new seats
+    pub balance: u128,
+
+    /// Number of seats claimed so far
+    pub seats_claimed: u32,
+
+    /// Number of single-use invite codes not yet claimed or revoked
+    pub open_invites: u32,
+
+    /// False once the sponsor closes the pool and takes back its balance
+    pub active: bool,
+}
+
+/// CreatorStats is a snapshot of a creator's numbers for their dashboard
+/// Returned by get_creator_stats()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct CreatorStats {
+    /// Everything paid out to the creator (streams and tips, after fees)
+    pub total_earned: u128,
+
+    /// Fans with a live subscription
+    pub subscriber_count: u32,
+
+    /// Published catalogue items
+    pub published_content: u32,
+
+    /// Number of tips received
+    pub tip_count: u32,
+
+    /// DOT received as tips (after fees) - included in total_earned
+    pub total_tips: u128,
+}
+
+/// RevenueShare is one collaborator's cut of a creator's earnings
+/// A podcast or band registered as one creator lists every member here
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct RevenueShare {
+    /// Wallet that receives this share of every payout
+    pub payee: ink::primitives::H160,
+
+    /// Share of each payout in basis points (2,500 = 25%)
+    pub share_bps: u16,
 }
 
 /// Custom error types for our contract
@@ -84,37 +696,139 @@ pub enum Error {
     /// Trying to access a creator that doesn't exist
     CreatorNotFound,
 
-    /// Fan is already subscribed to this creator
+    /// Fan already has a funded subscription to this creator (use top_up)
     SubscriptionAlreadyExists,
 
     /// Trying to access a subscription that doesn't exist
     SubscriptionNotFound,
 
-    /// Fan doesn't have active subscription to access content
+    /// Fan has never subscribed to this creator
     SubscriptionRequired,
 
+    /// Fan's subscription ran out or was cancelled - resubscribe or top up
+    SubscriptionExpired,
+
+    /// Fan's plan tier is below what the content requires
+    TierTooLow,
+
+    /// Catalogue item doesn't exist or has been unpublished
+    ContentNotFound,
+
+    /// Catalogue item has an empty CID
+    InvalidContent,
+
     /// Not enough DOT sent with the transaction
     InsufficientPayment,
 
+    /// Payment is not a whole number of billing periods of the plan
+    PaymentMismatch,
+
+    /// Trying to access a plan that doesn't exist for this creator
+    PlanNotFound,
+
+    /// Plan has been retired and no longer accepts new subscribers
+    PlanInactive,
+
+    /// Plan has a zero price or zero-length billing period
+    InvalidPlan,
+
+    /// Creator already has the maximum number of plans
+    TooManyPlans,
+
+    /// Subscription ledger would break deposited = claimed + refunded + outstanding
+    LedgerImbalance,
+
     /// Mathematical overflow (safety check)
     Overflow,
 
     /// Transfer of DOT failed
     TransferFailed,
+
+    /// Caller is not the contract owner
+    NotOwner,
+
+    /// Caller is not the pending owner of an ownership transfer
+    NotPendingOwner,
+
+    /// Caller lacks the role this action requires
+    MissingRole,
+
+    /// Contract is paused - only cancellations are allowed
+    ContractPaused,
+
+    /// Platform fee is above MAX_PLATFORM_FEE_BPS
+    FeeTooHigh,
+
+    /// Runtime rejected the new code hash (e.g., no code uploaded for it)
+    UpgradeFailed,
+
+    /// Storage is already at the current STORAGE_VERSION
+    AlreadyMigrated,
+
+    /// Revenue split shares don't add up to 100%, or list a payee twice or
+    /// with a zero share
+    InvalidSplit,
+
+    /// Revenue split has more than MAX_SPLIT_PAYEES payees
+    TooManyPayees,
+
+    /// Content price must be above zero
+    InvalidPrice,
+
+    /// Catalogue item has no one-time price - subscribe to open it
+    NotForSale,
+
+    /// Fan already bought this catalogue item
+    AlreadyPurchased,
+
+    /// Creator has no coupon with this code (or it was revoked)
+    CouponNotFound,
+
+    /// Coupon is past its expiry time
+    CouponExpired,
+
+    /// Coupon has reached its maximum number of redemptions
+    CouponExhausted,
+
+    /// Coupon already exists, has an out-of-range discount, or would make
+    /// the plan free
+    InvalidCoupon,
+
+    /// Gifted or sponsored subscriptions can't be topped up - the payer's
+    /// refund would include the fan's own DOT
+    GiftedSubscription,
+
+    /// Gift recipient is the payer - use subscribe() instead
+    InvalidRecipient,
+
+    /// Sponsorship pool doesn't exist
+    PoolNotFound,
+
+    /// Sponsorship pool was closed by its sponsor
+    PoolClosed,
+
+    /// Caller is not the pool's sponsor
+    NotSponsor,
+
+    /// Caller isn't on the pool's allowlist and has no valid invite code
+    NotInvited,
+
+    /// Caller already claimed a seat from this pool
+    SeatAlreadyClaimed,
+
+    /// Pool balance can't cover another seat
+    PoolExhausted,
 }
 
 // 🎪 BLOCKCHAIN EVENTS
 // Events are like notifications that the frontend can listen to
 // They're emitted when important things happen in the contract
 
-// TODO: Fix H160 compatibility with ink! events
-// Temporarily commented out to resolve compilation issues
-
-/*
 /// Emitted when a new creator joins the platform
 #[ink::event]
 pub struct CreatorRegistered {
     /// Address of the new creator (indexed for efficient searching)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// Display name of the creator
@@ -125,38 +839,121 @@ pub struct CreatorRegistered {
 #[ink::event]
 pub struct SubscriptionCreated {
     /// Fan's wallet address (indexed)
+    #[ink(topic)]
     pub fan: ink::primitives::H160,
 
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
-    /// Monthly rate in DOT (for display)
-    pub monthly_rate: u128,
+    /// Plan the fan subscribed to
+    pub plan_id: u32,
 
     /// Total DOT deposited
     pub total_deposited: u128,
+
+    /// End of the free trial, if the fan got one - streaming starts then
+    pub trial_ends_at: Option<u64>,
 }
 
-/// Emitted when a creator claims their earnings
+/// Emitted when a creator defines a new subscription plan
 #[ink::event]
-pub struct EarningsClaimed {
+pub struct PlanCreated {
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
-    /// Fan who was paying (indexed)
-    pub fan: ink::primitives::H160,
+    /// Id of the new plan
+    pub plan_id: u32,
 
-    /// Amount of DOT claimed
-    pub amount: u128,
+    /// Price of one billing period
+    pub price: u128,
+
+    /// How often the price is charged
+    pub period: BillingPeriod,
 }
 
-/// Emitted when a fan cancels their subscription
+/// Emitted when a creator edits or retires a subscription plan
 #[ink::event]
-pub struct SubscriptionCancelled {
-    /// Fan's wallet address (indexed)
-    pub fan: ink::primitives::H160,
+pub struct PlanUpdated {
+    /// Creator's wallet address (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Id of the updated plan
+    pub plan_id: u32,
+
+    /// Whether the plan still accepts new subscribers
+    pub active: bool,
+}
+
+/// Emitted when a fan adds funds to an existing subscription
+#[ink::event]
+pub struct SubscriptionToppedUp {
+    /// Fan's wallet address (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Creator's wallet address (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Amount of DOT added
+    pub amount: u128,
+
+    /// Timestamp when the extended deposit runs out
+    pub paid_until: u64,
+}
+
+/// Emitted when a creator claims their earnings
+#[ink::event]
+pub struct EarningsClaimed {
+    /// Creator's wallet address (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Fan who was paying (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Amount of DOT claimed
+    pub amount: u128,
+}
+
+/// Emitted when a creator claims from many subscribers in one transfer
+#[ink::event]
+pub struct BatchEarningsClaimed {
+    /// Creator's wallet address (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Total DOT claimed across the batch
+    pub amount: u128,
+
+    /// Number of subscriptions in the batch that had earnings to claim
+    pub subscriptions: u32,
+}
+
+/// Emitted when a creator claims earnings held back during a pause
+#[ink::event]
+pub struct HeldEarningsClaimed {
+    /// Creator's wallet address (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Amount of DOT claimed
+    pub amount: u128,
+}
+
+/// Emitted when a fan cancels their subscription
+#[ink::event]
+pub struct SubscriptionCancelled {
+    /// Fan's wallet address (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
 
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// Amount of DOT refunded to fan
@@ -167,12 +964,329 @@ pub struct SubscriptionCancelled {
 #[ink::event]
 pub struct ContentAdded {
     /// Creator's wallet address (indexed)
+    #[ink(topic)]
     pub creator: ink::primitives::H160,
 
     /// IPFS hash of the content
     pub content_hash: String,
 }
-*/
+
+/// Emitted when a fan sends a creator a one-off tip
+#[ink::event]
+pub struct TipReceived {
+    /// Fan who sent the tip (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Creator who received it (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Amount the creator received, after the platform fee
+    pub amount: u128,
+
+    /// Optional IPFS hash of a thank-you note from the fan
+    pub message_cid: Option<String>,
+}
+
+/// Emitted when a creator prices a catalogue item (or takes it off sale)
+#[ink::event]
+pub struct ContentPriceUpdated {
+    /// Creator who owns the item (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Id of the item
+    pub item_id: u32,
+
+    /// New one-time price (None = subscribers only)
+    pub price: Option<u128>,
+}
+
+/// Emitted when a fan buys a catalogue item outright
+#[ink::event]
+pub struct ContentPurchased {
+    /// Fan who bought the item (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Creator who sold it (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Id of the item
+    pub item_id: u32,
+
+    /// Amount the creator received, after the platform fee
+    pub amount: u128,
+}
+
+/// Emitted when someone pays for a subscription on a fan's behalf
+/// Followed by the fan's SubscriptionCreated
+#[ink::event]
+pub struct SubscriptionGifted {
+    /// Account that paid and gets any refund (indexed)
+    #[ink(topic)]
+    pub payer: ink::primitives::H160,
+
+    /// Fan who received the subscription (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Creator subscribed to (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Plan the gift is for
+    pub plan_id: u32,
+
+    /// Total DOT deposited by the payer
+    pub total_deposited: u128,
+}
+
+/// Emitted when a sponsor opens a sponsorship pool
+#[ink::event]
+pub struct SponsorshipPoolCreated {
+    /// Id of the new pool
+    pub pool_id: u32,
+
+    /// Sponsor who funds it (indexed)
+    #[ink(topic)]
+    pub sponsor: ink::primitives::H160,
+
+    /// Creator the seats are for (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Plan every seat subscribes to
+    pub plan_id: u32,
+
+    /// Initial deposit
+    pub balance: u128,
+}
+
+/// Emitted when a beneficiary claims a seat from a pool
+/// Followed by the beneficiary's SubscriptionCreated
+#[ink::event]
+pub struct SponsorshipClaimed {
+    /// Pool the seat came from
+    pub pool_id: u32,
+
+    /// Beneficiary who now has a subscription (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Creator subscribed to (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// DOT moved from the pool into the subscription
+    pub amount: u128,
+}
+
+/// Emitted when a sponsor closes a pool
+#[ink::event]
+pub struct SponsorshipPoolClosed {
+    /// Id of the closed pool
+    pub pool_id: u32,
+
+    /// Sponsor who got the remaining balance back (indexed)
+    #[ink(topic)]
+    pub sponsor: ink::primitives::H160,
+
+    /// DOT returned to the sponsor
+    pub refunded: u128,
+}
+
+/// Emitted when a fan subscribes with a coupon
+#[ink::event]
+pub struct CouponRedeemed {
+    /// Fan who used the coupon (indexed)
+    #[ink(topic)]
+    pub fan: ink::primitives::H160,
+
+    /// Creator who issued it (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// Keccak-256 hash of the coupon code
+    pub code_hash: [u8; 32],
+
+    /// Plan the fan subscribed to
+    pub plan_id: u32,
+
+    /// Discounted price of one period
+    pub price_per_period: u128,
+
+    /// Redemptions so far, including this one
+    pub redemptions: u32,
+}
+
+/// Emitted when the owner changes the platform fee
+#[ink::event]
+pub struct PlatformFeeUpdated {
+    /// Fee before the change, in basis points
+    pub previous_fee_bps: u16,
+
+    /// New fee, in basis points (100 = 1%)
+    pub fee_bps: u16,
+}
+
+/// Emitted when the owner points platform fee withdrawals at a new address
+#[ink::event]
+pub struct TreasuryUpdated {
+    /// New treasury address (indexed)
+    #[ink(topic)]
+    pub treasury: ink::primitives::H160,
+}
+
+/// Emitted when accumulated platform fees are sent to the treasury
+#[ink::event]
+pub struct PlatformFeesWithdrawn {
+    /// Treasury address that received the fees (indexed)
+    #[ink(topic)]
+    pub treasury: ink::primitives::H160,
+
+    /// Amount of DOT withdrawn
+    pub amount: u128,
+}
+
+/// Emitted when the owner nominates a new owner (step one of two)
+#[ink::event]
+pub struct OwnershipTransferStarted {
+    /// Current owner (indexed)
+    #[ink(topic)]
+    pub owner: ink::primitives::H160,
+
+    /// Account that must call accept_ownership() (indexed)
+    #[ink(topic)]
+    pub pending_owner: ink::primitives::H160,
+}
+
+/// Emitted when the pending owner accepts ownership (step two of two)
+#[ink::event]
+pub struct OwnershipTransferred {
+    /// Previous owner (indexed)
+    #[ink(topic)]
+    pub previous_owner: ink::primitives::H160,
+
+    /// New owner (indexed)
+    #[ink(topic)]
+    pub new_owner: ink::primitives::H160,
+}
+
+/// Emitted when a role is granted to an account
+#[ink::event]
+pub struct RoleGranted {
+    /// Account that received the role (indexed)
+    #[ink(topic)]
+    pub account: ink::primitives::H160,
+
+    /// Role that was granted
+    pub role: Role,
+
+    /// Owner or admin who granted it
+    pub granted_by: ink::primitives::H160,
+}
+
+/// Emitted when a role is taken away from an account
+#[ink::event]
+pub struct RoleRevoked {
+    /// Account that lost the role (indexed)
+    #[ink(topic)]
+    pub account: ink::primitives::H160,
+
+    /// Role that was revoked
+    pub role: Role,
+
+    /// Owner or admin who revoked it
+    pub revoked_by: ink::primitives::H160,
+}
+
+/// Emitted when an admin pauses the contract
+#[ink::event]
+pub struct Paused {
+    /// Admin who pulled the switch (indexed)
+    #[ink(topic)]
+    pub by: ink::primitives::H160,
+}
+
+/// Emitted when an admin unpauses the contract
+#[ink::event]
+pub struct Unpaused {
+    /// Admin who lifted the pause (indexed)
+    #[ink(topic)]
+    pub by: ink::primitives::H160,
+}
+
+/// Emitted when a creator changes how their earnings are split
+#[ink::event]
+pub struct RevenueSplitUpdated {
+    /// Creator whose split changed (indexed)
+    #[ink(topic)]
+    pub creator: ink::primitives::H160,
+
+    /// New payees and shares (empty = everything goes to the creator)
+    pub payees: Vec<RevenueShare>,
+}
+
+/// Emitted when a payee withdraws payouts whose transfer had failed
+#[ink::event]
+pub struct PayeeBalanceWithdrawn {
+    /// Payee's wallet address (indexed)
+    #[ink(topic)]
+    pub payee: ink::primitives::H160,
+
+    /// Amount of DOT withdrawn
+    pub amount: u128,
+}
+
+/// Emitted when the owner swaps the contract code
+#[ink::event]
+pub struct Upgraded {
+    /// Hash of the code the contract now runs
+    pub code_hash: ink::primitives::H256,
+}
+
+/// Emitted when the owner marks a storage migration as finished
+#[ink::event]
+pub struct StorageMigrated {
+    /// Storage version before the migration
+    pub from_version: u32,
+
+    /// Storage version after the migration (STORAGE_VERSION)
+    pub to_version: u32,
+}
+
+// ⚙️ CONTRACT LIMITS
+// Upper bounds that keep storage and query costs predictable
+
+/// Maximum number of plans a single creator can define
+/// Keeps get_creator_plans() within a single query's gas budget
+pub const MAX_PLANS_PER_CREATOR: u32 = 16;
+
+/// Maximum number of entries returned by one page of a list query
+/// Larger `limit` values are clamped to this
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Highest platform fee the owner can set, in basis points (1,000 = 10%)
+/// Enforced in code so creators know the fee can never exceed it
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Longest free trial a plan can offer, in days
+pub const MAX_TRIAL_DAYS: u32 = 90;
+
+/// Maximum number of payees in a creator's revenue split
+/// Every payout makes one transfer per payee, so this bounds claim gas
+pub const MAX_SPLIT_PAYEES: u32 = 10;
+
+/// Layout version of the records this code reads and writes
+/// Bump it whenever a stored struct changes shape, and add a migration
+/// Contracts deployed before versioning have no version cell: that's version 1
+pub const STORAGE_VERSION: u32 = 2;
 
 // 🏗️ MAIN CONTRACT STRUCTURE
 // This is the "database" that lives on the blockchain
@@ -182,10 +1296,16 @@ mod creator_treasury_pop {
     use super::*;
 
     // Use H160 directly to match the environment's account type
-    use ink::primitives::H160;
+    use ink::primitives::{H160, H256};
+    use ink::storage::traits::StorageKey;
 
     /// The main contract storage
     /// Think of this as a database with tables for creators and subscriptions
+    ///
+    /// **Layout rule**: plain fields are packed into one root cell that every
+    /// call decodes first. Version 1 contracts stored only `creator_count`
+    /// there, so the root must keep exactly that field - anything added since
+    /// lives in its own cell, a Mapping or (for single values) a Lazy.
     #[ink(storage)]
     pub struct CreatorTreasuryPop {
         /// Maps creator wallet address → their profile information
@@ -200,22 +1320,226 @@ mod creator_treasury_pop {
         /// Value: Subscription (payment details, timing, etc.)
         subscriptions: Mapping<(H160, H160), Subscription>,
 
+        /// Maps (creator_address, plan_id) → plan details
+        /// Like a "plans" table where each creator owns a numbered set of rows
+        /// Key: (creator's AccountId, plan id)
+        /// Value: SubscriptionPlan (name, price, billing period, perks)
+        plans: Mapping<(H160, u32), SubscriptionPlan>,
+
+        /// Maps (fan_address, creator_address, index) → ended subscription
+        /// Like a "subscription_history" table - one row per closed subscription
+        /// Key: (fan's AccountId, creator's AccountId, 0-based archive index)
+        /// Value: ArchivedSubscription (compact summary of the old record)
+        subscription_history: Mapping<(H160, H160, u32), ArchivedSubscription>,
+
+        /// Maps (fan_address, creator_address) → number of archived subscriptions
+        /// Next archive index for the pair
+        history_count: Mapping<(H160, H160), u32>,
+
+        /// Maps creator id → creator wallet address, in registration order
+        /// Mapping can't be iterated, so this index is what makes listing possible
+        /// Key: sequential id (0 to creator_count - 1)
+        /// Value: creator's AccountId
+        creator_ids: Mapping<u32, H160>,
+
+        /// Maps (creator_address, index) → fan address
+        /// Per-creator list of fans with a live subscription ("who subscribes to me?")
+        /// Key: (creator's AccountId, 0-based position)
+        /// Value: fan's AccountId
+        subscribers: Mapping<(H160, u32), H160>,
+
+        /// Maps creator_address → number of entries in `subscribers`
+        subscriber_count: Mapping<H160, u32>,
+
+        /// Maps (fan_address, index) → creator address
+        /// Per-fan list of creators they subscribe to ("what am I subscribed to?")
+        /// Key: (fan's AccountId, 0-based position)
+        /// Value: creator's AccountId
+        fan_subscriptions: Mapping<(H160, u32), H160>,
+
+        /// Maps fan_address → number of entries in `fan_subscriptions`
+        fan_subscription_count: Mapping<H160, u32>,
+
+        /// Maps (fan_address, creator_address) → positions in both lists
+        /// Value: (index in creator's `subscribers`, index in fan's `fan_subscriptions`)
+        /// Lets a cancelled subscription be removed from both lists without a scan
+        index_positions: Mapping<(H160, H160), (u32, u32)>,
+
+        /// Maps (creator_address, item_id) → catalogue item
+        /// Like a "content" table where each creator owns a numbered set of rows
+        /// Key: (creator's AccountId, item id)
+        /// Value: ContentItem (CID, title, type, required tier)
+        content_items: Mapping<(H160, u32), ContentItem>,
+
+        /// Maps (creator_address, index) → item id
+        /// Per-creator list of published items (unpublished ones are swap-removed)
+        published_content: Mapping<(H160, u32), u32>,
+
+        /// Maps creator_address → number of entries in `published_content`
+        published_count: Mapping<H160, u32>,
+
+        /// Maps (creator_address, item_id) → index in `published_content`
+        content_positions: Mapping<(H160, u32), u32>,
+
         /// Total number of registered creators
         /// Used for displaying stats and iteration
         creator_count: u32,
+
+        /// Account with full control of the contract (initially the deployer)
+        /// Changed with the two-step transfer_ownership / accept_ownership
+        /// Unset on a contract upgraded from version 1 - see get_owner()
+        owner: Lazy<H160>,
+
+        /// Account nominated by transfer_ownership() that hasn't accepted yet
+        pending_owner: Lazy<Option<H160>>,
+
+        /// Maps (account, role) → true for every granted role
+        /// Like a "role_members" table; revoking removes the entry
+        /// The owner holds every role implicitly
+        roles: Mapping<(H160, Role), bool>,
+
+        /// Address that receives withdrawn platform fees (defaults to the owner)
+        treasury: Lazy<H160>,
+
+        /// Share of every creator payout kept by the platform, in basis points
+        platform_fee_bps: Lazy<u16>,
+
+        /// Platform fees collected but not yet withdrawn to the treasury
+        platform_balance: Lazy<u128>,
+
+        /// Emergency circuit breaker - blocks subscribing, top-ups and claims
+        /// Fans can always cancel, so deposits are never stuck
+        paused: Lazy<bool>,
+
+        /// Maps creator_address → earnings that vested in subscriptions
+        /// cancelled during a pause, waiting for claim_held_earnings()
+        held_earnings: Mapping<H160, u128>,
+
+        /// Maps (creator_address, price_per_period) → id of the retired plan
+        /// holding their migrated version 1 subscriptions at that rate
+        legacy_plans: Mapping<(H160, u128), u32>,
+
+        /// Layout version of the records in storage
+        /// Lags behind STORAGE_VERSION after an upgrade until the migration
+        /// is completed
+        storage_version: Lazy<u32>,
+
+        /// Number of migrated version 1 creators written back into
+        /// `creator_ids` (version 1 counted them but kept no index)
+        migrated_creator_ids: Lazy<u32>,
+
+        /// Maps creator_address → collaborators sharing their payouts
+        /// No entry means the creator receives everything
+        revenue_splits: Mapping<H160, Vec<RevenueShare>>,
+
+        /// Maps payee_address → payouts whose transfer failed, owed until
+        /// withdraw_payee_balance() - one bad payee never blocks a payout
+        payee_balances: Mapping<H160, u128>,
+
+        /// Maps creator_address → number of tips received
+        tip_count: Mapping<H160, u32>,
+
+        /// Maps creator_address → DOT received as tips (after fees)
+        total_tips: Mapping<H160, u128>,
+
+        /// Maps (creator_address, item_id) → one-time price of the item
+        /// No entry means the item is for subscribers only
+        content_prices: Mapping<(H160, u32), u128>,
+
+        /// Maps (fan_address, creator_address, item_id) → true once bought
+        /// Purchases never expire, even if the item is later unpublished
+        purchases: Mapping<(H160, H160, u32), bool>,
+
+        /// Maps (creator_address, plan_id) → free trial length in days
+        /// No entry means the plan has no trial
+        plan_trials: Mapping<(H160, u32), u32>,
+
+        /// Maps (fan_address, creator_address) → true once the fan had a trial
+        /// One trial per fan per creator, whichever plan it was on
+        trials_used: Mapping<(H160, H160), bool>,
+
+        /// Maps (creator_address, code hash) → coupon
+        /// Like a "coupons" table keyed by the Keccak-256 hash of each code
+        coupons: Mapping<(H160, [u8; 32]), Coupon>,
+
+        /// Maps pool id → sponsorship pool
+        /// Like a "pools" table with sequential ids (0 to pool_count - 1)
+        pools: Mapping<u32, SponsorshipPool>,
+
+        /// Number of sponsorship pools ever created (next free pool id)
+        pool_count: Lazy<u32>,
+
+        /// Maps (pool_id, account) → true for allowlisted beneficiaries
+        pool_allowlist: Mapping<(u32, H160), bool>,
+
+        /// Maps (pool_id, code hash) → true for each unused invite code
+        /// Every code admits one claim and is removed when used
+        pool_invites: Mapping<(u32, [u8; 32]), bool>,
+
+        /// Maps (pool_id, account) → true once the account claimed its seat
+        pool_seats: Mapping<(u32, H160), bool>,
+
+        /// Maps (fan_address, creator_address) → pool paying for the live
+        /// subscription; refunds go back into the pool instead of to the fan
+        sponsored_by: Mapping<(H160, H160), u32>,
     }
 
     impl CreatorTreasuryPop {
         /// 🏁 CONSTRUCTOR - Called once when contract is deployed
         /// This initializes the contract with empty storage
         /// No parameters needed - we start with zero creators and subscriptions
+        /// The deployer becomes the owner and treasury, with no platform fee
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self {
+            let owner: H160 = Self::env().caller();
+            let mut contract = Self {
                 creators: Mapping::default(),
                 subscriptions: Mapping::default(),
+                plans: Mapping::default(),
+                subscription_history: Mapping::default(),
+                history_count: Mapping::default(),
+                creator_ids: Mapping::default(),
+                subscribers: Mapping::default(),
+                subscriber_count: Mapping::default(),
+                fan_subscriptions: Mapping::default(),
+                fan_subscription_count: Mapping::default(),
+                index_positions: Mapping::default(),
+                content_items: Mapping::default(),
+                published_content: Mapping::default(),
+                published_count: Mapping::default(),
+                content_positions: Mapping::default(),
                 creator_count: 0,
-            }
+                owner: Lazy::default(),
+                pending_owner: Lazy::default(),
+                roles: Mapping::default(),
+                treasury: Lazy::default(),
+                platform_fee_bps: Lazy::default(),
+                platform_balance: Lazy::default(),
+                paused: Lazy::default(),
+                held_earnings: Mapping::default(),
+                legacy_plans: Mapping::default(),
+                storage_version: Lazy::default(),
+                migrated_creator_ids: Lazy::default(),
+                revenue_splits: Mapping::default(),
+                payee_balances: Mapping::default(),
+                tip_count: Mapping::default(),
+                total_tips: Mapping::default(),
+                content_prices: Mapping::default(),
+                purchases: Mapping::default(),
+                plan_trials: Mapping::default(),
+                trials_used: Mapping::default(),
+                coupons: Mapping::default(),
+                pools: Mapping::default(),
+                pool_count: Lazy::default(),
+                pool_allowlist: Mapping::default(),
+                pool_invites: Mapping::default(),
+                pool_seats: Mapping::default(),
+                sponsored_by: Mapping::default(),
+            };
+            contract.owner.set(&owner);
+            contract.treasury.set(&owner);
+            contract.storage_version.set(&STORAGE_VERSION);
+            contract
         }
 
         // 👥 CREATOR MANAGEMENT FUNCTIONS
@@ -252,22 +1576,25 @@ mod creator_treasury_pop {
                 content_hash: None, // No content uploaded yet
                 total_earned: 0,    // Haven't earned anything yet
                 created_at: now,    // Record registration time
+                plan_count: 0,      // Plans are added with create_plan()
+                content_count: 0,   // Items are added with add_content()
             };
 
             // Store the profile in our creators mapping
             // This permanently saves it to the blockchain
             self.creators.insert(caller, &profile);
 
-            // Increment our creator counter for stats
+            // Add the creator to the ordered index and bump the counter
+            // The counter doubles as the next free id in creator_ids
+            self.creator_ids.insert(self.creator_count, &caller);
             self.creator_count += 1;
 
             // Emit event to notify frontend about new creator
             // The frontend can listen for this and update the UI
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(CreatorRegistered {
-            //     creator: caller,
-            //     name,
-            // });
+            self.env().emit_event(CreatorRegistered {
+                creator: caller,
+                name,
+            });
 
             Ok(())
         }
@@ -310,672 +1637,4767 @@ mod creator_treasury_pop {
             self.creators.get(account).is_some()
         }
 
-        // 💰 SUBSCRIPTION MANAGEMENT FUNCTIONS
-        // These functions handle the core subscription and payment logic
-
-        /// Fan subscribes to a creator with monthly payment
-        /// This is the core function that starts the streaming payment relationship
-        ///
-        /// The function is marked "payable" which means it can receive DOT
-        /// The DOT sent with the transaction becomes the subscription balance
+        /// Creator shares their earnings with collaborators
+        /// Every later payout is divided between the payees by share; the
+        /// rounding remainder goes to the first payee. The creator is only paid
+        /// if they list themselves. An empty list pays the creator everything.
         ///
         /// Parameters:
-        /// - creator: Wallet address of creator to subscribe to
-        /// - monthly_rate: How much DOT per month (in Planck units)
+        /// - payees: Collaborators and their shares, adding up to 10,000 bps
         ///
         /// Returns:
-        /// - Ok(()) if subscription successful
-        /// - Err(...) for various failure conditions
-        #[ink(message, payable)]
-        pub fn subscribe(&mut self, creator: H160, monthly_rate: u128) -> Result<(), Error> {
-            let fan: H160 = self.env().caller();
-            let payment = self.env().transferred_value();
-
-            // Verify the creator exists
+        /// - Ok(()) if the split is saved
+        /// - Err(CreatorNotFound) if caller is not a registered creator
+        /// - Err(TooManyPayees) if more than MAX_SPLIT_PAYEES payees
+        /// - Err(InvalidSplit) if shares don't add up to 100%, a share is zero
+        ///   or a payee is listed twice
+        #[ink(message)]
+        pub fn set_revenue_split(&mut self, payees: Vec<RevenueShare>) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
             if self.creators.get(creator).is_none() {
                 return Err(Error::CreatorNotFound);
             }
-
-            // Check if fan is already subscribed to this creator
-            let subscription_key = (fan, creator);
-            if self.subscriptions.get(subscription_key).is_some() {
-                return Err(Error::SubscriptionAlreadyExists);
+            if payees.len() > MAX_SPLIT_PAYEES as usize {
+                return Err(Error::TooManyPayees);
             }
 
-            // Convert payment from U256 to u128 for comparison and storage
-            let payment_u128: u128 = payment.try_into().map_err(|_| Error::Overflow)?;
-            
-            // Verify sufficient payment was sent
-            // Fan should send at least the monthly rate
-            if payment_u128 < monthly_rate {
-                return Err(Error::InsufficientPayment);
+            if payees.is_empty() {
+                self.revenue_splits.remove(creator);
+            } else {
+                let mut total_bps: u128 = 0;
+                for (i, share) in payees.iter().enumerate() {
+                    let duplicate = payees[..i].iter().any(|other| other.payee == share.payee);
+                    if share.share_bps == 0 || duplicate {
+                        return Err(Error::InvalidSplit);
+                    }
+                    total_bps += u128::from(share.share_bps);
+                }
+                if total_bps != BPS_DENOMINATOR {
+                    return Err(Error::InvalidSplit);
+                }
+                self.revenue_splits.insert(creator, &payees);
             }
 
-            // Calculate streaming rate per second
-            // 30 days = 30 * 24 * 60 * 60 = 2,592,000 seconds
-            let seconds_per_month = 30u128 * 24 * 60 * 60;
-            let rate_per_second = monthly_rate / seconds_per_month;
-
-            // Get current timestamp for subscription start
-            let now = self.env().block_timestamp();
-
-            // Create subscription record
-            let subscription = Subscription {
-                total_deposited: payment_u128,
-                rate_per_second,
-                last_claim_time: now, // Creator can claim immediately
-                start_time: now,
-            };
-
-            // Store subscription in mapping
-            self.subscriptions.insert(subscription_key, &subscription);
-
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(SubscriptionCreated {
-            //     fan,
-            //     creator,
-            //     monthly_rate,
-            //     total_deposited: payment_u128,
-            // });
+            self.env().emit_event(RevenueSplitUpdated { creator, payees });
 
             Ok(())
         }
 
-        /// Get subscription details between a fan and creator
-        /// Used by frontend to display subscription status
-        ///
-        /// Parameters:
-        /// - fan: Fan's wallet address
-        /// - creator: Creator's wallet address
+        /// Get a creator's revenue split (empty if the creator keeps everything)
+        #[ink(message)]
+        pub fn get_revenue_split(&self, creator: H160) -> Vec<RevenueShare> {
+            self.revenue_splits.get(creator).unwrap_or_default()
+        }
+
+        /// Payee withdraws payouts that couldn't be sent when they were made
+        /// (e.g. a new account whose share was below the existential deposit)
         ///
         /// Returns:
-        /// - Ok(Subscription) if subscription exists
-        /// - Err(SubscriptionNotFound) if no subscription
+        /// - Ok(amount) withdrawn (0 if nothing is owed)
+        /// - Err(TransferFailed) if the transfer failed again
+        /// - Err(ContractPaused) if the contract is paused
         #[ink(message)]
-        pub fn get_subscription(
-            &self,
-            fan: H160,
-            creator: H160,
-        ) -> Result<Subscription, Error> {
-            let subscription_key = (fan, creator);
-            self.subscriptions
-                .get(subscription_key)
-                .ok_or(Error::SubscriptionNotFound)
+        pub fn withdraw_payee_balance(&mut self) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let payee: H160 = self.env().caller();
+            let amount = self.payee_balances.take(payee).unwrap_or(0);
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            if self.env().transfer(payee, amount.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(PayeeBalanceWithdrawn { payee, amount });
+
+            Ok(amount)
         }
 
-        // 💰 EARNINGS & VESTING FUNCTIONS
-        // These functions handle time-based vesting and earnings claims
+        /// Get the payouts owed to a payee whose transfers failed
+        #[ink(message)]
+        pub fn get_payee_balance(&self, payee: H160) -> u128 {
+            self.payee_balances.get(payee).unwrap_or(0)
+        }
 
-        /// Creator claims their vested earnings from a specific fan
-        /// This implements the core "streaming payment" logic of our platform
-        ///
-        /// **Key Concept: Time-Based Vesting**
-        /// Instead of paying creators monthly, fans' payments "vest" (become claimable)
-        /// every second. This creates a smooth, continuous payment stream.
-        ///
-        /// **Vesting Formula**:
-        /// ```
-        /// vested_amount = (current_time - last_claim_time) * rate_per_second
-        /// ```
-        ///
-        /// **Example**:
-        /// - Fan pays 5 DOT/month = 1,929 Planck/second
-        /// - After 1 hour (3600 seconds): 3600 * 1,929 = 6,944,400 Planck vested
-        /// - Creator can claim ~0.007 DOT after 1 hour
+        // 🏷️ PLAN MANAGEMENT FUNCTIONS
+        // These functions let creators price their own subscription tiers
+
+        /// Creator defines a new subscription plan (e.g., "Supporter", "Pro", "Patron")
+        /// Fans pick one of these plans when they subscribe
         ///
         /// Parameters:
-        /// - fan: Wallet address of the fan who is paying
+        /// - name: Display name of the tier
+        /// - price: Price of one billing period (in Planck units)
+        /// - period: How often the price is charged
+        /// - perks: Short descriptions of what the tier includes
         ///
         /// Returns:
-        /// - Ok(amount_claimed) if successful
-        /// - Err(...) for various failure conditions
+        /// - Ok(plan_id) if the plan was created
+        /// - Err(CreatorNotFound) if caller is not a registered creator
+        /// - Err(InvalidPlan) if price or period is zero
+        /// - Err(TooManyPlans) if the creator already has MAX_PLANS_PER_CREATOR plans
         #[ink(message)]
-        pub fn claim_earnings(&mut self, fan: H160) -> Result<u128, Error> {
+        pub fn create_plan(
+            &mut self,
+            name: String,
+            price: u128,
+            period: BillingPeriod,
+            perks: Vec<String>,
+        ) -> Result<u32, Error> {
             let creator: H160 = self.env().caller();
-            let subscription_key = (fan, creator);
-
-            // Get subscription details - this verifies the subscription exists
-            let mut subscription = self
-                .subscriptions
-                .get(subscription_key)
-                .ok_or(Error::SubscriptionNotFound)?;
-
-            // Get current blockchain timestamp
-            // Block timestamps are in milliseconds since Unix epoch
-            let now = self.env().block_timestamp();
 
-            // Calculate time elapsed since last claim (in milliseconds)
-            // This is the "vesting period" - how long payments have been accumulating
-            let time_elapsed = now - subscription.last_claim_time;
+            // Only registered creators can sell plans
+            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
 
-            // Convert milliseconds to seconds for our rate calculation
-            // Our rate_per_second is calculated in Planck units per second
-            let seconds_elapsed = time_elapsed / 1000;
+            // A free or zero-length plan would break the streaming rate calculation
+            if price == 0 || period.duration_ms() == 0 {
+                return Err(Error::InvalidPlan);
+            }
 
-            // Calculate how much has vested during this period
-            // This is the core streaming payment calculation
-            let vested_amount = seconds_elapsed as u128 * subscription.rate_per_second;
+            if profile.plan_count >= MAX_PLANS_PER_CREATOR {
+                return Err(Error::TooManyPlans);
+            }
 
-            // Safety check: Don't claim more than what's available in the subscription
-            // This prevents over-claiming if there are calculation errors
-            let available_balance = subscription.total_deposited;
-            let claimable_amount = if vested_amount > available_balance {
-                available_balance // Claim everything that's left
-            } else {
-                vested_amount // Claim the vested amount
+            // Plan ids are assigned sequentially per creator
+            let plan_id = profile.plan_count;
+            let plan = SubscriptionPlan {
+                name,
+                price,
+                period,
+                perks,
+                active: true,
+                tier: 0,
+                min_commitment_periods: 0,
             };
+            self.plans.insert((creator, plan_id), &plan);
 
-            // If nothing to claim, return early (no point in processing empty claims)
-            if claimable_amount == 0 {
-                return Ok(0);
-            }
+            profile.plan_count += 1;
+            self.creators.insert(creator, &profile);
 
-            // Update subscription state to reflect the claim
-            subscription.last_claim_time = now; // Reset the vesting clock
-            subscription.total_deposited = subscription
-                .total_deposited
-                .saturating_sub(claimable_amount);
+            self.env().emit_event(PlanCreated {
+                creator,
+                plan_id,
+                price,
+                period,
+            });
 
-            // Save the updated subscription state (even if balance is 0)
-            // TODO: Implement proper cleanup mechanism for empty subscriptions
-            self.subscriptions.insert(subscription_key, &subscription);
+            Ok(plan_id)
+        }
 
-            // Update creator's total earnings for display purposes
-            // This is a running total of all earnings across all fans
-            if let Some(mut profile) = self.creators.get(creator) {
-                profile.total_earned = profile.total_earned.saturating_add(claimable_amount);
-                self.creators.insert(creator, &profile);
-            }
+        /// Creator edits an existing plan
+        /// Changes only apply to new subscriptions - existing fans keep the
+        /// rate they signed up for
+        ///
+        /// Parameters:
+        /// - plan_id: Id of the plan to edit
+        /// - name: New display name
+        /// - price: New price of one billing period (in Planck units)
+        /// - period: New billing period
+        /// - perks: New list of perks
+        ///
+        /// Returns:
+        /// - Ok(()) if the plan was updated
+        /// - Err(PlanNotFound) if the caller has no plan with this id
+        /// - Err(InvalidPlan) if price or period is zero
+        #[ink(message)]
+        pub fn update_plan(
+            &mut self,
+            plan_id: u32,
+            name: String,
+            price: u128,
+            period: BillingPeriod,
+            perks: Vec<String>,
+        ) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
 
-            // Transfer the claimed DOT from contract to creator's wallet
-            // This is the actual payment - moving tokens on the blockchain
-            if self
-                .env()
-                .transfer(creator, claimable_amount.into())
-                .is_err()
-            {
-                return Err(Error::TransferFailed);
+            if price == 0 || period.duration_ms() == 0 {
+                return Err(Error::InvalidPlan);
             }
 
-            // Emit event for frontend notification
-            // The frontend can listen for this to update the UI in real-time
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(EarningsClaimed {
-            //     creator,
-            //     fan,
-            //     amount: claimable_amount,
-            // });
+            plan.name = name;
+            plan.price = price;
+            plan.period = period;
+            plan.perks = perks;
+            self.plans.insert((creator, plan_id), &plan);
 
-            Ok(claimable_amount)
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
+
+            Ok(())
         }
 
-        /// Fan cancels subscription and gets refund for unused time
-        /// This implements fair refunds - fans only pay for time they were subscribed
+        /// Creator opens or closes a plan for new subscribers
+        /// Plans are never deleted so existing subscriptions can still reference them
         ///
-        /// **Key Concept: Fair Refund Calculation**
-        /// When a fan cancels, they should get back money for time they haven't used yet.
-        /// We calculate how much time has passed since subscription started, and refund
-        /// the portion that hasn't "vested" to the creator yet.
-        ///
-        /// **Refund Formula**:
-        /// ```
-        /// total_vested = (current_time - start_time) * rate_per_second
-        /// refund_amount = total_deposited - total_vested
-        /// ```
+        /// Parameters:
+        /// - plan_id: Id of the plan
+        /// - active: false to retire the plan, true to offer it again
         ///
-        /// **Example**:
-        /// - Fan pays 5 DOT for 1 month subscription
-        /// - After 15 days, fan cancels
-        /// - Refund = 5 DOT - (15 days worth of vesting) = ~2.5 DOT
+        /// Returns:
+        /// - Ok(()) if successful
+        /// - Err(PlanNotFound) if the caller has no plan with this id
+        #[ink(message)]
+        pub fn set_plan_active(&mut self, plan_id: u32, active: bool) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+
+            plan.active = active;
+            self.plans.insert((creator, plan_id), &plan);
+
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active,
+            });
+
+            Ok(())
+        }
+
+        /// Creator sets the access tier of a plan
+        /// Takes effect immediately for everyone on the plan
         ///
         /// Parameters:
-        /// - creator: Wallet address of creator to unsubscribe from
+        /// - plan_id: Id of the plan
+        /// - tier: Access level (higher tiers unlock everything lower tiers do)
         ///
         /// Returns:
-        /// - Ok(refund_amount) if successful
-        /// - Err(...) for various failure conditions
+        /// - Ok(()) if successful
+        /// - Err(PlanNotFound) if the caller has no plan with this id
         #[ink(message)]
-        pub fn cancel_subscription(&mut self, creator: H160) -> Result<u128, Error> {
-            let fan: H160 = self.env().caller();
-            let subscription_key = (fan, creator);
+        pub fn set_plan_tier(&mut self, plan_id: u32, tier: u32) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
 
-            // Get subscription details - this verifies the subscription exists
-            let subscription = self
-                .subscriptions
-                .get(subscription_key)
-                .ok_or(Error::SubscriptionNotFound)?;
+            plan.tier = tier;
+            self.plans.insert((creator, plan_id), &plan);
 
-            // Get current blockchain timestamp
-            let now = self.env().block_timestamp();
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
 
-            // Calculate total time elapsed since subscription started
-            // This is different from claim_earnings which uses last_claim_time
-            // Here we need the TOTAL elapsed time to calculate fair refund
-            let total_elapsed = now - subscription.start_time;
-            let seconds_elapsed = total_elapsed / 1000; // Convert to seconds
+            Ok(())
+        }
 
-            // Calculate total amount that has vested since subscription started
-            // This includes both claimed and unclaimed vested amounts
-            let total_vested = seconds_elapsed as u128 * subscription.rate_per_second;
+        /// Creator sets the minimum commitment of a plan
+        /// Only applies to fans who subscribe afterwards
+        ///
+        /// **Why**: Without a commitment a fan could subscribe, read the content
+        /// and cancel straight away for an almost full refund. With a commitment
+        /// of N periods, cancelling early still pays the creator N periods.
+        ///
+        /// Parameters:
+        /// - plan_id: Id of the plan
+        /// - periods: Number of billing periods fans commit to (0 to disable)
+        ///
+        /// Returns:
+        /// - Ok(()) if successful
+        /// - Err(PlanNotFound) if the caller has no plan with this id
+        /// - Err(Overflow) if the committed amount doesn't fit in a u128
+        #[ink(message)]
+        pub fn set_plan_commitment(&mut self, plan_id: u32, periods: u32) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let mut plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+
+            plan.price
+                .checked_mul(u128::from(periods))
+                .ok_or(Error::Overflow)?;
+            plan.min_commitment_periods = periods;
+            self.plans.insert((creator, plan_id), &plan);
+
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
 
-            // Calculate refund amount (what hasn't vested yet)
-            // Use saturating_sub to prevent underflow if calculation errors occur
-            let refund_amount = if total_vested >= subscription.total_deposited {
-                0 // Subscription fully vested, no refund available
-            } else {
-                subscription.total_deposited - total_vested
-            };
+            Ok(())
+        }
 
-            // Mark subscription as cancelled by setting balance to 0
-            // TODO: Implement proper subscription removal mechanism
-            let cancelled_subscription = Subscription {
-                total_deposited: 0,
-                rate_per_second: 0,
-                last_claim_time: subscription.last_claim_time,
-                start_time: subscription.start_time,
-            };
-            self.subscriptions.insert(subscription_key, &cancelled_subscription);
+        /// Creator offers a free trial on a plan (e.g., 7 days)
+        /// Applies to fans who subscribe afterwards. During the trial the fan
+        /// can open content but nothing vests; when it ends the deposit starts
+        /// streaming on its own. Each fan gets one trial per creator.
+        ///
+        /// Parameters:
+        /// - plan_id: Id of the plan
+        /// - trial_days: Length of the trial (0 to disable)
+        ///
+        /// Returns:
+        /// - Ok(()) if successful
+        /// - Err(PlanNotFound) if the caller has no plan with this id
+        /// - Err(InvalidPlan) if the trial is longer than MAX_TRIAL_DAYS
+        #[ink(message)]
+        pub fn set_plan_trial(&mut self, plan_id: u32, trial_days: u32) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
 
-            // Transfer refund to fan if there's anything to refund
-            // Only attempt transfer if refund_amount > 0 to save gas
-            if refund_amount > 0 {
-                if self.env().transfer(fan, refund_amount.into()).is_err() {
-                    return Err(Error::TransferFailed);
-                }
+            if trial_days > MAX_TRIAL_DAYS {
+                return Err(Error::InvalidPlan);
+            }
+            if trial_days == 0 {
+                self.plan_trials.remove((creator, plan_id));
+            } else {
+                self.plan_trials.insert((creator, plan_id), &trial_days);
             }
 
-            // Emit event for frontend notification
-            // This allows the UI to show cancellation confirmation
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(SubscriptionCancelled {
-            //     fan,
-            //     creator,
-            //     refund_amount,
-            // });
+            self.env().emit_event(PlanUpdated {
+                creator,
+                plan_id,
+                active: plan.active,
+            });
 
-            Ok(refund_amount)
+            Ok(())
         }
 
-        // 🔒 CONTENT MANAGEMENT FUNCTIONS
-        // These functions handle exclusive content upload and access control
+        /// Get the free trial length of a plan in days (0 = no trial)
+        #[ink(message)]
+        pub fn get_plan_trial(&self, creator: H160, plan_id: u32) -> u32 {
+            self.plan_trials.get((creator, plan_id)).unwrap_or(0)
+        }
 
-        /// Creator adds exclusive content (IPFS hash)
-        /// Only registered creators can call this for their own profile
-        ///
-        /// **Key Concept: IPFS Content Storage**
-        /// We don't store actual content (videos, images, text) on the blockchain
-        /// because that would be extremely expensive. Instead, we store IPFS hashes.
-        ///
-        /// **IPFS (InterPlanetary File System)**:
-        /// - Decentralized file storage network
-        /// - Content is identified by cryptographic hash
-        /// - Immutable - content can't be changed once uploaded
-        /// - Distributed - stored across many nodes worldwide
-        ///
-        /// **Example IPFS Hash**: "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u"
-        ///
-        /// **Access Pattern**:
-        /// 1. Creator uploads content to IPFS → gets hash
-        /// 2. Creator calls this function with the hash
-        /// 3. Hash is stored on blockchain (small, cheap)
-        /// 4. Fans can retrieve hash if subscribed
-        /// 5. Fans use hash to download content from IPFS
+        /// Check whether a fan has already had their free trial with a creator
+        #[ink(message)]
+        pub fn has_used_trial(&self, fan: H160, creator: H160) -> bool {
+            self.trials_used.contains((fan, creator))
+        }
+
+        /// Creator sets up a coupon for a promotion
+        /// Only the hash of the code goes on-chain; fans pass the code itself to
+        /// subscribe(). Works on any of the creator's plans.
         ///
         /// Parameters:
-        /// - content_hash: IPFS hash pointing to the exclusive content
+        /// - code_hash: Keccak-256 hash of the coupon code (see hash_coupon_code)
+        /// - discount: Percentage (1-99) or fixed amount off each period
+        /// - max_redemptions: How many subscriptions can use it (0 = unlimited)
+        /// - expires_at: When it stops working (Unix timestamp), or None
         ///
         /// Returns:
-        /// - Ok(()) if successful
+        /// - Ok(()) if the coupon was created
         /// - Err(CreatorNotFound) if caller is not a registered creator
+        /// - Err(InvalidCoupon) if the code is taken or the discount is out of range
         #[ink(message)]
-        pub fn add_exclusive_content(&mut self, content_hash: String) -> Result<(), Error> {
+        pub fn create_coupon(
+            &mut self,
+            code_hash: [u8; 32],
+            discount: Discount,
+            max_redemptions: u32,
+            expires_at: Option<u64>,
+        ) -> Result<(), Error> {
             let creator: H160 = self.env().caller();
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
 
-            // Verify caller is a registered creator
-            // This is access control - only creators can add content to their profile
-            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
-
-            // Update the creator's profile with the new content hash
-            // This overwrites any previous content - in a full implementation,
-            // you might want to support multiple content pieces
-            profile.content_hash = Some(content_hash.clone());
-
-            // Save the updated profile back to blockchain storage
-            self.creators.insert(creator, &profile);
+            let valid_discount = match discount {
+                Discount::Percent(percent) => (1..100).contains(&percent),
+                Discount::Fixed(amount) => amount > 0,
+            };
+            if !valid_discount || self.coupons.contains((creator, code_hash)) {
+                return Err(Error::InvalidCoupon);
+            }
 
-            // Emit event for frontend notification
-            // This allows the UI to show that new content is available
-            // TODO: Fix event H160 compatibility
-            // self.env().emit_event(ContentAdded {
-            //     creator,
-            //     content_hash,
-            // });
+            let coupon = Coupon {
+                discount,
+                max_redemptions,
+                redemptions: 0,
+                expires_at,
+            };
+            self.coupons.insert((creator, code_hash), &coupon);
 
             Ok(())
         }
 
-        /// Get creator's exclusive content (if caller is subscribed)
-        /// This implements the content gating logic - the core value proposition
+        /// Creator withdraws a coupon; subscriptions that used it keep their price
         ///
-        /// **Key Concept: Content Gating**
-        /// This is what makes our platform valuable - exclusive content is only
-        /// accessible to paying subscribers. This function enforces that rule.
+        /// Returns:
+        /// - Ok(()) if the coupon was removed
+        /// - Err(CouponNotFound) if the caller has no coupon with this hash
+        #[ink(message)]
+        pub fn revoke_coupon(&mut self, code_hash: [u8; 32]) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            self.coupons
+                .take((creator, code_hash))
+                .ok_or(Error::CouponNotFound)?;
+            Ok(())
+        }
+
+        /// Get a creator's coupon, including how often it has been redeemed
+        #[ink(message)]
+        pub fn get_coupon(&self, creator: H160, code_hash: [u8; 32]) -> Result<Coupon, Error> {
+            self.coupons
+                .get((creator, code_hash))
+                .ok_or(Error::CouponNotFound)
+        }
+
+        /// Hash a coupon code the same way subscribe() does (Keccak-256)
+        /// Run as a query, so the code is never sent in a transaction
+        #[ink(message)]
+        pub fn hash_coupon_code(&self, code: String) -> [u8; 32] {
+            Self::hash_code(&code)
+        }
+
+        /// Get a single plan offered by a creator
         ///
-        /// **Access Control Flow**:
-        /// 1. Check if creator exists and has content
-        /// 2. Check if caller (fan) has active subscription to creator
-        /// 3. If subscribed: return IPFS hash (fan can download content)
-        /// 4. If not subscribed: return error (fan must subscribe first)
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        /// - plan_id: Id of the plan
         ///
-        /// **Frontend Usage**:
-        /// ```typescript
-        /// try {
-        ///   const contentHash = await contract.query.getCreatorContent(creatorAddress);
-        ///   // Fan is subscribed - show content or download from IPFS
-        ///   displayContent(contentHash);
-        /// } catch (error) {
-        ///   // Fan not subscribed - show subscription prompt
-        ///   showSubscribeButton();
-        /// }
-        /// ```
+        /// Returns:
+        /// - Ok(SubscriptionPlan) if the plan exists
+        /// - Err(PlanNotFound) otherwise
+        #[ink(message)]
+        pub fn get_plan(&self, creator: H160, plan_id: u32) -> Result<SubscriptionPlan, Error> {
+            self.plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)
+        }
+
+        /// Get every plan a creator has defined, including retired ones
+        /// Used by the frontend to render the creator's pricing table
         ///
         /// Parameters:
-        /// - creator: Wallet address of creator whose content to access
+        /// - creator: Wallet address of the creator
         ///
         /// Returns:
-        /// - Ok(content_hash) if caller has active subscription
-        /// - Err(SubscriptionRequired) if not subscribed
-        /// - Err(CreatorNotFound) if creator doesn't exist or has no content
+        /// - Vector of (plan_id, SubscriptionPlan) pairs, ordered by id
+        /// - Empty vector if the creator doesn't exist
         #[ink(message)]
-        pub fn get_creator_content(&self, creator: H160) -> Result<String, Error> {
+        pub fn get_creator_plans(&self, creator: H160) -> Vec<(u32, SubscriptionPlan)> {
+            let plan_count = self
+                .creators
+                .get(creator)
+                .map(|profile| profile.plan_count)
+                .unwrap_or(0);
+
+            (0..plan_count)
+                .filter_map(|plan_id| {
+                    self.plans
+                        .get((creator, plan_id))
+                        .map(|plan| (plan_id, plan))
+                })
+                .collect()
+        }
+
+        // 💰 SUBSCRIPTION MANAGEMENT FUNCTIONS
+        // These functions handle the core subscription and payment logic
+
+        /// Fan subscribes to one of a creator's plans
+        /// This is the core function that starts the streaming payment relationship
+        ///
+        /// The function is marked "payable" which means it can receive DOT
+        /// The DOT sent with the transaction becomes the subscription balance
+        /// and must cover a whole number of the plan's billing periods
+        ///
+        /// A fan can subscribe again after cancelling, or once their previous
+        /// deposit has fully streamed out - the old record is archived first
+        /// (see get_subscription_history)
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of creator to subscribe to
+        /// - plan_id: Id of the creator's plan (see get_creator_plans)
+        /// - coupon: Optional coupon code from the creator; its discount applies
+        ///   to every period of this subscription
+        ///
+        /// Returns:
+        /// - Ok(()) if subscription successful
+        /// - Err(PlanNotFound) / Err(PlanInactive) if the plan can't be joined
+        /// - Err(InsufficientPayment) if less than one period was sent
+        /// - Err(PaymentMismatch) if the payment isn't a multiple of the
+        ///   (discounted) plan price
+        /// - Err(SubscriptionAlreadyExists) if the current subscription is still funded
+        /// - Err(CouponNotFound) / Err(CouponExpired) / Err(CouponExhausted) /
+        ///   Err(InvalidCoupon) if the coupon can't be used
+        /// - Err(ContractPaused) if the contract is paused
+        #[ink(message, payable)]
+        pub fn subscribe(
+            &mut self,
+            creator: H160,
+            plan_id: u32,
+            coupon: Option<String>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
             let fan: H160 = self.env().caller();
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+            self.open_subscription(fan, creator, plan_id, coupon, payment, None)?;
+            Ok(())
+        }
+
+        /// Pay for a subscription on someone else's behalf
+        /// The recipient gets a normal subscription (content access, free trial
+        /// if the plan has one) and can cancel it, but any refund goes back to
+        /// the payer. Gifts can't be topped up; once it runs out the recipient
+        /// can subscribe themselves.
+        ///
+        /// Parameters:
+        /// - recipient: Fan who receives the subscription
+        /// - creator: Wallet address of creator to subscribe to
+        /// - plan_id: Id of the creator's plan
+        ///
+        /// Payment:
+        /// - Same rules as subscribe(): whole periods, at least the commitment
+        ///
+        /// Returns:
+        /// - Ok(()) if the recipient is now subscribed
+        /// - Err(InvalidRecipient) if the payer gifts to themselves
+        /// - Err(SubscriptionAlreadyExists) if the recipient already has a
+        ///   funded subscription to this creator
+        /// - Other errors as for subscribe()
+        #[ink(message, payable)]
+        pub fn gift_subscription(
+            &mut self,
+            recipient: H160,
+            creator: H160,
+            plan_id: u32,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let payer: H160 = self.env().caller();
+            if recipient == payer {
+                return Err(Error::InvalidRecipient);
+            }
+
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+            let total_deposited =
+                self.open_subscription(recipient, creator, plan_id, None, payment, Some(payer))?;
+
+            self.env().emit_event(SubscriptionGifted {
+                payer,
+                fan: recipient,
+                creator,
+                plan_id,
+                total_deposited,
+            });
+
+            Ok(())
+        }
+
+        // 🎓 SPONSORSHIP FUNCTIONS
+        // Sponsors fund subscriptions for many beneficiaries (scholarships,
+        // team licences) from one pool
+
+        /// Sponsor opens a pool of seats on one of a creator's plans
+        /// The DOT sent with the call is the pool's starting balance. Each
+        /// claimed seat takes plan price × periods_per_seat out of the pool.
+        ///
+        /// Parameters:
+        /// - creator: Creator the seats are for
+        /// - plan_id: Plan every seat subscribes to
+        /// - periods_per_seat: Billing periods each seat pays for
+        ///
+        /// Returns:
+        /// - Ok(pool_id) of the new pool
+        /// - Err(PlanNotFound) / Err(PlanInactive) if the plan can't be joined
+        /// - Err(InvalidPlan) if periods_per_seat is zero
+        /// - Err(ContractPaused) if the contract is paused
+        #[ink(message, payable)]
+        pub fn create_pool(
+            &mut self,
+            creator: H160,
+            plan_id: u32,
+            periods_per_seat: u32,
+        ) -> Result<u32, Error> {
+            self.ensure_not_paused()?;
+            let sponsor: H160 = self.env().caller();
+            let balance: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            let plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            if !plan.active {
+                return Err(Error::PlanInactive);
+            }
+            if periods_per_seat == 0 {
+                return Err(Error::InvalidPlan);
+            }
+
+            let pool_id = self.pool_count.get().unwrap_or(0);
+            self.pool_count
+                .set(&pool_id.checked_add(1).ok_or(Error::Overflow)?);
+            let pool = SponsorshipPool {
+                sponsor,
+                creator,
+                plan_id,
+                periods_per_seat,
+                balance,
+                seats_claimed: 0,
+                open_invites: 0,
+                active: true,
+            };
+            self.pools.insert(pool_id, &pool);
+
+            self.env().emit_event(SponsorshipPoolCreated {
+                pool_id,
+                sponsor,
+                creator,
+                plan_id,
+                balance,
+            });
+
+            Ok(pool_id)
+        }
+
+        /// Sponsor adds DOT to an open pool
+        ///
+        /// Returns:
+        /// - Ok(balance) with the pool's new balance
+        /// - Err(NotSponsor) if caller doesn't run the pool
+        /// - Err(PoolClosed) if the pool was closed
+        #[ink(message, payable)]
+        pub fn fund_pool(&mut self, pool_id: u32) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let amount: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            let mut pool = self.sponsor_pool(pool_id)?;
+            pool.balance = pool.balance.checked_add(amount).ok_or(Error::Overflow)?;
+            self.pools.insert(pool_id, &pool);
+
+            Ok(pool.balance)
+        }
+
+        /// Sponsor allowlists beneficiaries who may claim a seat
+        ///
+        /// Returns:
+        /// - Ok(()) if the accounts were added
+        /// - Err(NotSponsor) if caller doesn't run the pool
+        /// - Err(PoolClosed) if the pool was closed
+        #[ink(message)]
+        pub fn add_beneficiaries(
+            &mut self,
+            pool_id: u32,
+            beneficiaries: Vec<H160>,
+        ) -> Result<(), Error> {
+            self.sponsor_pool(pool_id)?;
+            for beneficiary in beneficiaries {
+                self.pool_allowlist.insert((pool_id, beneficiary), &true);
+            }
+            Ok(())
+        }
+
+        /// Sponsor takes a beneficiary off the allowlist
+        /// A seat they already claimed keeps streaming until it runs out
+        #[ink(message)]
+        pub fn remove_beneficiary(&mut self, pool_id: u32, beneficiary: H160) -> Result<(), Error> {
+            self.sponsor_pool(pool_id)?;
+            self.pool_allowlist.remove((pool_id, beneficiary));
+            Ok(())
+        }
+
+        /// Sponsor issues single-use invite codes, for beneficiaries whose
+        /// addresses they don't know yet
+        /// Give each invitee their own code: a claim reveals the code on-chain,
+        /// so it is used up by the claim.
+        ///
+        /// Parameters:
+        /// - pool_id: Pool the codes admit to
+        /// - code_hashes: Keccak-256 hash of each code (see hash_coupon_code)
+        ///
+        /// Returns:
+        /// - Ok(()) if the codes can now be claimed with
+        /// - Err(NotSponsor) if caller doesn't run the pool
+        /// - Err(PoolClosed) if the pool was closed
+        #[ink(message)]
+        pub fn add_invites(
+            &mut self,
+            pool_id: u32,
+            code_hashes: Vec<[u8; 32]>,
+        ) -> Result<(), Error> {
+            let mut pool = self.sponsor_pool(pool_id)?;
+            for code_hash in code_hashes {
+                if !self.pool_invites.contains((pool_id, code_hash)) {
+                    self.pool_invites.insert((pool_id, code_hash), &true);
+                    pool.open_invites = pool.open_invites.saturating_add(1);
+                }
+            }
+            self.pools.insert(pool_id, &pool);
+            Ok(())
+        }
+
+        /// Sponsor withdraws an unused invite code
+        #[ink(message)]
+        pub fn revoke_invite(&mut self, pool_id: u32, code_hash: [u8; 32]) -> Result<(), Error> {
+            let mut pool = self.sponsor_pool(pool_id)?;
+            if self.pool_invites.take((pool_id, code_hash)).is_some() {
+                pool.open_invites = pool.open_invites.saturating_sub(1);
+                self.pools.insert(pool_id, &pool);
+            }
+            Ok(())
+        }
+
+        /// Beneficiary claims their seat: a subscription paid from the pool
+        /// Allowed for allowlisted accounts, or anyone with an unused invite
+        /// code, which the claim uses up. One seat per account per pool.
+        /// Cancelling returns the unused balance to the pool, not to the
+        /// beneficiary.
+        ///
+        /// Parameters:
+        /// - pool_id: Pool to claim from
+        /// - invite_code: The caller's invite code (None if allowlisted)
+        ///
+        /// Returns:
+        /// - Ok(()) if the caller is now subscribed
+        /// - Err(PoolNotFound) / Err(PoolClosed) if the pool can't be used
+        /// - Err(NotInvited) if caller is neither allowlisted nor invited
+        /// - Err(SeatAlreadyClaimed) if caller already had a seat from this pool
+        /// - Err(PoolExhausted) if the balance can't cover another seat
+        /// - Other errors as for subscribe()
+        #[ink(message)]
+        pub fn claim_sponsorship(
+            &mut self,
+            pool_id: u32,
+            invite_code: Option<String>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let fan: H160 = self.env().caller();
+            let mut pool = self.pools.get(pool_id).ok_or(Error::PoolNotFound)?;
+            if !pool.active {
+                return Err(Error::PoolClosed);
+            }
+
+            // Allowlisted callers need no code; anyone else uses one up
+            let invite = if self.pool_allowlist.contains((pool_id, fan)) {
+                None
+            } else {
+                let code = invite_code.ok_or(Error::NotInvited)?;
+                let invite = (pool_id, Self::hash_code(&code));
+                if !self.pool_invites.contains(invite) {
+                    return Err(Error::NotInvited);
+                }
+                Some(invite)
+            };
+            if self.pool_seats.contains((pool_id, fan)) {
+                return Err(Error::SeatAlreadyClaimed);
+            }
+
+            let plan = self
+                .plans
+                .get((pool.creator, pool.plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            let seat_cost = plan
+                .price
+                .checked_mul(u128::from(pool.periods_per_seat.max(plan.min_commitment_periods)))
+                .ok_or(Error::Overflow)?;
+            if seat_cost > pool.balance {
+                return Err(Error::PoolExhausted);
+            }
+
+            let sponsor = Some(pool.sponsor);
+            self.open_subscription(fan, pool.creator, pool.plan_id, None, seat_cost, sponsor)?;
+            self.sponsored_by.insert((fan, pool.creator), &pool_id);
+
+            if let Some(invite) = invite {
+                self.pool_invites.remove(invite);
+                pool.open_invites = pool.open_invites.saturating_sub(1);
+            }
+            pool.balance -= seat_cost;
+            pool.seats_claimed = pool.seats_claimed.saturating_add(1);
+            self.pools.insert(pool_id, &pool);
+            self.pool_seats.insert((pool_id, fan), &true);
+
+            self.env().emit_event(SponsorshipClaimed {
+                pool_id,
+                fan,
+                creator: pool.creator,
+                amount: seat_cost,
+            });
+
+            Ok(())
+        }
+
+        /// Sponsor closes a pool and takes back its remaining balance
+        /// Seats already claimed keep streaming; if one is cancelled later, its
+        /// unused DOT goes straight to the sponsor
+        ///
+        /// Returns:
+        /// - Ok(refunded) with the DOT sent back to the sponsor
+        /// - Err(NotSponsor) if caller doesn't run the pool
+        /// - Err(PoolClosed) if the pool was already closed
+        #[ink(message)]
+        pub fn close_pool(&mut self, pool_id: u32) -> Result<u128, Error> {
+            let mut pool = self.sponsor_pool(pool_id)?;
+            let refunded = pool.balance;
+            pool.balance = 0;
+            pool.active = false;
+            self.pools.insert(pool_id, &pool);
+
+            if refunded > 0 && self.env().transfer(pool.sponsor, refunded.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(SponsorshipPoolClosed {
+                pool_id,
+                sponsor: pool.sponsor,
+                refunded,
+            });
+
+            Ok(refunded)
+        }
+
+        /// Get a sponsorship pool
+        #[ink(message)]
+        pub fn get_pool(&self, pool_id: u32) -> Result<SponsorshipPool, Error> {
+            self.pools.get(pool_id).ok_or(Error::PoolNotFound)
+        }
+
+        /// Check whether an account is allowlisted for a pool
+        #[ink(message)]
+        pub fn is_beneficiary(&self, pool_id: u32, account: H160) -> bool {
+            self.pool_allowlist.contains((pool_id, account))
+        }
+
+        /// Get the pool paying for a fan's live subscription, if it's sponsored
+        #[ink(message)]
+        pub fn get_sponsoring_pool(&self, fan: H160, creator: H160) -> Option<u32> {
+            self.sponsored_by.get((fan, creator))
+        }
+
+        /// Get subscription details between a fan and creator
+        /// Used by frontend to display subscription status
+        ///
+        /// Parameters:
+        /// - fan: Fan's wallet address
+        /// - creator: Creator's wallet address
+        ///
+        /// Returns:
+        /// - Ok(Subscription) if subscription exists
+        /// - Err(SubscriptionNotFound) if no subscription
+        #[ink(message)]
+        pub fn get_subscription(
+            &self,
+            fan: H160,
+            creator: H160,
+        ) -> Result<Subscription, Error> {
+            let subscription_key = (fan, creator);
+            self.subscriptions
+                .get(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)
+        }
+
+        /// Get the status of a fan's subscription to a creator as of now
+        /// Vesting is settled on a copy, so this is accurate without a transaction
+        ///
+        /// A live subscription is Active while its deposit is still streaming (Paused
+        /// if the contract is paused) and Depleted once it has fully streamed.
+        /// Without a live subscription the
+        /// most recent archived one decides between Cancelled and Depleted.
+        ///
+        /// Parameters:
+        /// - fan: Fan's wallet address
+        /// - creator: Creator's wallet address
+        ///
+        /// Returns:
+        /// - Ok(SubscriptionOverview) with status, claimable, remaining and paid_until
+        /// - Err(SubscriptionNotFound) if the fan never subscribed to this creator
+        #[ink(message)]
+        pub fn get_subscription_status(
+            &self,
+            fan: H160,
+            creator: H160,
+        ) -> Result<SubscriptionOverview, Error> {
+            if let Some(mut subscription) = self.subscriptions.get((fan, creator)) {
+                // Run-out time is fixed by the stored record; settling a dry
+                // stream would move it forward to now
+                let paid_until = subscription.paid_until();
+                subscription.settle(self.env().block_timestamp());
+
+                let remaining = subscription.unvested();
+                let status = if remaining == 0 {
+                    SubscriptionStatus::Depleted
+                } else if self.is_paused() {
+                    SubscriptionStatus::Paused
+                } else {
+                    SubscriptionStatus::Active
+                };
+
+                return Ok(SubscriptionOverview {
+                    status,
+                    claimable: subscription.claimable(),
+                    remaining,
+                    paid_until,
+                });
+            }
+
+            // Fall back to the latest ended subscription, which is fully settled
+            let count = self.history_count.get((fan, creator)).unwrap_or(0);
+            let last = count
+                .checked_sub(1)
+                .and_then(|index| self.subscription_history.get((fan, creator, index)))
+                .ok_or(Error::SubscriptionNotFound)?;
+            let status = match last.reason {
+                SubscriptionEnd::Cancelled => SubscriptionStatus::Cancelled,
+                SubscriptionEnd::Depleted => SubscriptionStatus::Depleted,
+            };
+
+            Ok(SubscriptionOverview {
+                status,
+                claimable: 0,
+                remaining: 0,
+                paid_until: last.ended_at,
+            })
+        }
+
+        /// Get every ended subscription between a fan and creator, oldest first
+        /// A fan who cancels and later resubscribes keeps their earlier records here
+        ///
+        /// Parameters:
+        /// - fan: Fan's wallet address
+        /// - creator: Creator's wallet address
+        ///
+        /// Returns:
+        /// - Vector of ArchivedSubscription (empty if the pair never closed one)
+        #[ink(message)]
+        pub fn get_subscription_history(
+            &self,
+            fan: H160,
+            creator: H160,
+        ) -> Vec<ArchivedSubscription> {
+            let count = self.history_count.get((fan, creator)).unwrap_or(0);
+            (0..count)
+                .filter_map(|index| self.subscription_history.get((fan, creator, index)))
+                .collect()
+        }
+
+        // 💰 EARNINGS & VESTING FUNCTIONS
+        // These functions handle time-based vesting and earnings claims
+
+        /// Creator claims their vested earnings from a specific fan
+        /// This implements the core "streaming payment" logic of our platform
+        ///
+        /// **Key Concept: Time-Based Vesting**
+        /// Instead of paying creators monthly, fans' payments "vest" (become claimable)
+        /// every millisecond. This creates a smooth, continuous payment stream.
+        ///
+        /// **Vesting Formula**:
+        /// ```text
+        /// total_vested += price_per_period * (current_time - settled_until) / period length
+        /// claimable     = total_vested - total_claimed
+        /// ```
+        /// (fractions of a Planck are carried forward, see Subscription::settle)
+        ///
+        /// **Example**:
+        /// - Fan pays 5 DOT (5,000,000,000,000 Planck) every 30 days
+        /// - After 1 hour: 5,000,000,000,000 * 3,600,000 / 2,592,000,000 = 6,944,444,444 Planck
+        /// - Creator can claim ~0.007 DOT after 1 hour
+        ///
+        /// **Platform Fee**:
+        /// The platform keeps platform_fee_bps of every payout; the creator is
+        /// sent the rest. With a 5% fee the creator above receives ~0.0066 DOT.
+        ///
+        /// Parameters:
+        /// - fan: Wallet address of the fan who is paying
+        ///
+        /// Returns:
+        /// - Ok(amount_paid) sent to the creator after the platform fee
+        /// - Err(ContractPaused) if the contract is paused
+        /// - Err(...) for various failure conditions
+        #[ink(message)]
+        pub fn claim_earnings(&mut self, fan: H160) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let creator: H160 = self.env().caller();
+            let subscription_key = (fan, creator);
+
+            // Get subscription details - this verifies the subscription exists
+            let mut subscription = self
+                .subscriptions
+                .get(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)?;
+
+            // Bring the ledger up to the current block timestamp
+            // Block timestamps are in milliseconds since Unix epoch
+            subscription.settle(self.env().block_timestamp());
+
+            // Everything vested and not yet paid out can be claimed
+            let claimable_amount = subscription.claimable();
+            subscription.total_claimed += claimable_amount;
+
+            // Save the updated subscription state (even if balance is 0)
+            // Empty subscriptions are archived when the fan cancels or restarts
+            self.save_subscription(subscription_key, &subscription)?;
+
+            // If nothing to claim, return early (no point in processing empty claims)
+            if claimable_amount == 0 {
+                return Ok(0);
+            }
+
+            let paid = self.pay_creator(creator, claimable_amount)?;
+
+            // Emit event for frontend notification
+            // The frontend can listen for this to update the UI in real-time
+            self.env().emit_event(EarningsClaimed {
+                creator,
+                fan,
+                amount: paid,
+            });
+
+            Ok(paid)
+        }
+
+        /// Creator claims vested earnings from every subscriber in one call
+        /// Each stream is settled individually, then the total is sent to the
+        /// creator in a single transfer
+        ///
+        /// **Gas Considerations**:
+        /// Cost grows with the number of subscribers. Creators with a large
+        /// audience should use claim_batch() to split the work over several
+        /// transactions.
+        ///
+        /// Returns:
+        /// - Ok(total_claimed) summed over all subscribers (0 if nothing vested)
+        /// - Err(CreatorNotFound) if caller is not a registered creator
+        /// - Err(ContractPaused) if the contract is paused
+        #[ink(message)]
+        pub fn claim_all(&mut self) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let creator: H160 = self.env().caller();
+            let total = self.subscriber_count.get(creator).unwrap_or(0);
+            let (claimed, _) = self.claim_range(creator, 0..total)?;
+            Ok(claimed)
+        }
+
+        /// Creator claims vested earnings from one batch of subscribers
+        /// Walk the whole list by passing the returned cursor back in as `offset`
+        /// until it equals get_subscribers()'s total
+        ///
+        /// **Example**:
+        /// ```typescript
+        /// let cursor = 0;
+        /// do {
+        ///   [claimed, cursor] = await contract.tx.claimBatch(cursor, 50);
+        /// } while (cursor < total);
+        /// ```
+        ///
+        /// Parameters:
+        /// - offset: Position in the creator's subscriber list to start from
+        /// - limit: Number of subscribers to settle (clamped to MAX_PAGE_SIZE)
+        ///
+        /// Returns:
+        /// - Ok((claimed, next_offset)) - amount paid out and where the next batch starts
+        /// - Err(CreatorNotFound) if caller is not a registered creator
+        /// - Err(ContractPaused) if the contract is paused
+        #[ink(message)]
+        pub fn claim_batch(&mut self, offset: u32, limit: u32) -> Result<(u128, u32), Error> {
+            self.ensure_not_paused()?;
+            let creator: H160 = self.env().caller();
+            let total = self.subscriber_count.get(creator).unwrap_or(0);
+            self.claim_range(creator, Self::page_range(offset, limit, total))
+        }
+
+        /// Creator claims the earnings held back from subscriptions that fans
+        /// cancelled while the contract was paused
+        ///
+        /// Returns:
+        /// - Ok(amount) paid after the platform fee (0 if nothing was held)
+        /// - Err(ContractPaused) if the contract is still paused
+        #[ink(message)]
+        pub fn claim_held_earnings(&mut self) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let creator: H160 = self.env().caller();
+            let held = self.held_earnings.take(creator).unwrap_or(0);
+            if held == 0 {
+                return Ok(0);
+            }
+
+            let paid = self.pay_creator(creator, held)?;
+            self.env().emit_event(HeldEarningsClaimed {
+                creator,
+                amount: paid,
+            });
+
+            Ok(paid)
+        }
+
+        /// Get the earnings held for a creator until claim_held_earnings()
+        #[ink(message)]
+        pub fn get_held_earnings(&self, creator: H160) -> u128 {
+            self.held_earnings.get(creator).unwrap_or(0)
+        }
+
+        /// Fan adds funds to an existing subscription
+        /// This extends the stream without cancelling, so the fan keeps their
+        /// subscription history and start date
+        ///
+        /// **Key Concept: Settle Before Topping Up**
+        /// Before the new DOT is added, everything that has already vested is
+        /// recorded in the ledger and the vesting clock is brought up to date.
+        /// Without this, a stream that ran dry last week would "vest" that idle
+        /// week retroactively as soon as fresh funds arrive.
+        ///
+        /// **Example**:
+        /// - Fan deposited 5 DOT for 30 days, 20 days have passed
+        /// - Fan tops up 5 DOT → ~3.33 DOT stays claimable by the creator,
+        ///   ~6.67 DOT keeps streaming
+        /// - Runway extends to ~40 days from the original start
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator whose subscription to extend
+        ///
+        /// Returns:
+        /// - Ok(paid_until) - timestamp (milliseconds) when the new deposit runs out
+        /// - Err(SubscriptionNotFound) if the fan has no subscription to this creator
+        /// - Err(InsufficientPayment) if no DOT was sent
+        /// - Err(GiftedSubscription) if someone else paid for the subscription
+        /// - Err(ContractPaused) if the contract is paused
+        #[ink(message, payable)]
+        pub fn top_up(&mut self, creator: H160) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let fan: H160 = self.env().caller();
+            let payment = self.env().transferred_value();
+
+            // Convert payment from U256 to u128 for comparison and storage
+            let payment_u128: u128 = payment.try_into().map_err(|_| Error::Overflow)?;
+            if payment_u128 == 0 {
+                return Err(Error::InsufficientPayment);
+            }
+
+            let subscription_key = (fan, creator);
+            let mut subscription = self
+                .subscriptions
+                .get(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)?;
+            if subscription.payer.is_some() {
+                return Err(Error::GiftedSubscription);
+            }
+
+            // Record what has already streamed so the new funds only vest from now on
+            subscription.settle(self.env().block_timestamp());
+
+            // Add the new funds to the prepaid balance
+            subscription.total_deposited = subscription
+                .total_deposited
+                .checked_add(payment_u128)
+                .ok_or(Error::Overflow)?;
+            self.save_subscription(subscription_key, &subscription)?;
+
+            let paid_until = subscription.paid_until();
+
+            self.env().emit_event(SubscriptionToppedUp {
+                fan,
+                creator,
+                amount: payment_u128,
+                paid_until,
+            });
+
+            Ok(paid_until)
+        }
+
+        /// Fan cancels subscription and gets refund for unused time
+        /// This implements fair refunds - fans only pay for time they were subscribed
+        ///
+        /// **Key Concept: Fair Refund Calculation**
+        /// When a fan cancels, they should get back money for time they haven't used yet.
+        /// The subscription is settled to the current time, the creator is paid
+        /// whatever has vested but not been claimed, and the fan gets the rest.
+        ///
+        /// **Refund Formula**:
+        /// ```
+        /// refund_amount = total_deposited - total_vested - total_refunded
+        /// ```
+        ///
+        /// **Example**:
+        /// - Fan pays 5 DOT for 1 month subscription
+        /// - After 15 days, fan cancels
+        /// - Refund = 5 DOT - (15 days worth of vesting) = ~2.5 DOT
+        ///
+        /// **Minimum Commitment**:
+        /// If the plan had a minimum commitment when the fan subscribed, the
+        /// creator keeps at least the committed amount. With a one-period
+        /// commitment the fan above would get nothing back after 15 days.
+        ///
+        /// The cancelled subscription is moved into the fan's history and the live
+        /// record is removed, so the fan can subscribe to this creator again later
+        ///
+        /// Cancelling works even while the contract is paused - it is the fans'
+        /// exit path, so their unvested balance can never be stuck. The
+        /// creator's vested share is then held for claim_held_earnings()
+        /// instead of being paid out.
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of creator to unsubscribe from
+        ///
+        /// Returns:
+        /// - Ok(refund_amount) if successful
+        /// - Err(...) for various failure conditions
+        #[ink(message)]
+        pub fn cancel_subscription(&mut self, creator: H160) -> Result<u128, Error> {
+            let fan: H160 = self.env().caller();
+
+            // Settle, pay the creator their share, refund the fan and archive
+            // This also verifies the subscription exists
+            let refund_amount =
+                self.close_subscription(fan, creator, SubscriptionEnd::Cancelled)?;
+
+            // Emit event for frontend notification
+            // This allows the UI to show cancellation confirmation
+            self.env().emit_event(SubscriptionCancelled {
+                fan,
+                creator,
+                refund_amount,
+            });
+
+            Ok(refund_amount)
+        }
+
+        // 🎁 TIP FUNCTIONS
+        // One-off payments that don't open a subscription
+
+        /// Fan sends a creator a one-time thank-you
+        /// The tip is paid out straight away, like a claim: the platform fee
+        /// applies and the creator's revenue split is honoured
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator to tip
+        /// - message_cid: Optional IPFS hash of a note for the creator
+        ///
+        /// Payment:
+        /// - Must send the tip amount with the transaction (any amount above 0)
+        ///
+        /// Returns:
+        /// - Ok(amount) with the DOT the creator received after the fee
+        /// - Err(ContractPaused) while the contract is paused
+        /// - Err(CreatorNotFound) if creator doesn't exist
+        /// - Err(InsufficientPayment) if no DOT was sent
+        #[ink(message, payable)]
+        pub fn tip(&mut self, creator: H160, message_cid: Option<String>) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let fan: H160 = self.env().caller();
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+            if payment == 0 {
+                return Err(Error::InsufficientPayment);
+            }
+
+            let amount = self.pay_creator(creator, payment)?;
+
+            let count = self.tip_count.get(creator).unwrap_or(0);
+            self.tip_count.insert(creator, &count.saturating_add(1));
+            let total = self.total_tips.get(creator).unwrap_or(0);
+            self.total_tips.insert(creator, &total.saturating_add(amount));
+
+            self.env().emit_event(TipReceived {
+                fan,
+                creator,
+                amount,
+                message_cid,
+            });
+
+            Ok(amount)
+        }
+
+        // 🔒 CONTENT MANAGEMENT FUNCTIONS
+        // These functions handle exclusive content upload and access control
+
+        /// Creator adds exclusive content (IPFS hash)
+        /// Only registered creators can call this for their own profile
+        /// This sets the single profile-level item; use add_content() to build
+        /// a catalogue of multiple items
+        ///
+        /// **Key Concept: IPFS Content Storage**
+        /// We don't store actual content (videos, images, text) on the blockchain
+        /// because that would be extremely expensive. Instead, we store IPFS hashes.
+        ///
+        /// **IPFS (InterPlanetary File System)**:
+        /// - Decentralized file storage network
+        /// - Content is identified by cryptographic hash
+        /// - Immutable - content can't be changed once uploaded
+        /// - Distributed - stored across many nodes worldwide
+        ///
+        /// **Example IPFS Hash**: "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u"
+        ///
+        /// **Access Pattern**:
+        /// 1. Creator uploads content to IPFS → gets hash
+        /// 2. Creator calls this function with the hash
+        /// 3. Hash is stored on blockchain (small, cheap)
+        /// 4. Fans can retrieve hash if subscribed
+        /// 5. Fans use hash to download content from IPFS
+        ///
+        /// Parameters:
+        /// - content_hash: IPFS hash pointing to the exclusive content
+        ///
+        /// Returns:
+        /// - Ok(()) if successful
+        /// - Err(CreatorNotFound) if caller is not a registered creator
+        #[ink(message)]
+        pub fn add_exclusive_content(&mut self, content_hash: String) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+
+            // Verify caller is a registered creator
+            // This is access control - only creators can add content to their profile
+            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            // Update the creator's profile with the new content hash
+            // This overwrites any previous content - in a full implementation,
+            // you might want to support multiple content pieces
+            profile.content_hash = Some(content_hash.clone());
+
+            // Save the updated profile back to blockchain storage
+            self.creators.insert(creator, &profile);
+
+            // Emit event for frontend notification
+            // This allows the UI to show that new content is available
+            self.env().emit_event(ContentAdded {
+                creator,
+                content_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Get creator's exclusive content (if caller is subscribed)
+        /// This implements the content gating logic - the core value proposition
+        ///
+        /// **Key Concept: Content Gating**
+        /// This is what makes our platform valuable - exclusive content is only
+        /// accessible to paying subscribers. This function enforces that rule.
+        ///
+        /// **Access Control Flow**:
+        /// 1. Check if creator exists and has content
+        /// 2. Check if caller (fan) has a live, still-funded subscription to creator
+        /// 3. If subscribed: return IPFS hash (fan can download content)
+        /// 4. If not subscribed: return error (fan must subscribe first)
+        ///
+        /// A cancelled or fully streamed subscription no longer grants access
+        ///
+        /// **Frontend Usage**:
+        /// ```typescript
+        /// try {
+        ///   const contentHash = await contract.query.getCreatorContent(creatorAddress);
+        ///   // Fan is subscribed - show content or download from IPFS
+        ///   displayContent(contentHash);
+        /// } catch (error) {
+        ///   // Fan not subscribed - show subscription prompt
+        ///   showSubscribeButton();
+        /// }
+        /// ```
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of creator whose content to access
+        ///
+        /// Returns:
+        /// - Ok(content_hash) if caller has active subscription
+        /// - Err(SubscriptionRequired) if caller never subscribed
+        /// - Err(SubscriptionExpired) if caller's subscription ran out or was cancelled
+        /// - Err(CreatorNotFound) if creator doesn't exist or has no content
+        #[ink(message)]
+        pub fn get_creator_content(&self, creator: H160) -> Result<String, Error> {
+            let fan: H160 = self.env().caller();
+
+            // Verify creator exists and has content
+            let profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            // Check if fan has active subscription to this creator
+            // This is the core gating mechanism - profile content is open to every tier
+            self.check_access(fan, creator, 0)?;
+
+            // Return content hash if available, or error if creator hasn't uploaded content yet
+            profile.content_hash.ok_or(Error::CreatorNotFound)
+        }
+
+        /// Get the IPFS hash of one catalogue item (if caller has access)
+        /// Same rules as get_content(): a live subscription of the item's tier,
+        /// or a purchase of the item, opens it
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        /// - item_id: Id of the item
+        ///
+        /// Returns:
+        /// - Ok(cid) if caller can open the item
+        /// - Errors as for get_content()
+        #[ink(message)]
+        pub fn get_creator_item_content(
+            &self,
+            creator: H160,
+            item_id: u32,
+        ) -> Result<String, Error> {
+            self.get_content(creator, item_id).map(|item| item.cid)
+        }
+
+        /// Creator adds an item to their content catalogue
+        /// Unlike add_exclusive_content(), every call creates a new item
+        ///
+        /// Parameters:
+        /// - cid: IPFS content identifier of the uploaded content
+        /// - title: Display title
+        /// - content_type: What kind of content the CID points to
+        /// - required_tier: Minimum plan tier needed to open it (0 = any subscriber)
+        ///
+        /// Returns:
+        /// - Ok(item_id) if the item was added
+        /// - Err(CreatorNotFound) if caller is not a registered creator
+        /// - Err(InvalidContent) if the CID is empty
+        #[ink(message)]
+        pub fn add_content(
+            &mut self,
+            cid: String,
+            title: String,
+            content_type: ContentType,
+            required_tier: u32,
+        ) -> Result<u32, Error> {
+            let creator: H160 = self.env().caller();
+            let mut profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            if cid.is_empty() {
+                return Err(Error::InvalidContent);
+            }
+
+            // Item ids are assigned sequentially per creator, like plan ids
+            let item_id = profile.content_count;
+            profile.content_count = item_id.checked_add(1).ok_or(Error::Overflow)?;
+
+            let item = ContentItem {
+                id: item_id,
+                cid,
+                title,
+                content_type,
+                created_at: self.env().block_timestamp(),
+                required_tier,
+                published: true,
+            };
+            self.content_items.insert((creator, item_id), &item);
+            self.creators.insert(creator, &profile);
+
+            // Newest items go to the end of the published list
+            let position = self.published_count.get(creator).unwrap_or(0);
+            self.published_content.insert((creator, position), &item_id);
+            self.published_count.insert(creator, &(position + 1));
+            self.content_positions.insert((creator, item_id), &position);
+
+            self.env().emit_event(ContentAdded {
+                creator,
+                content_hash: item.cid,
+            });
+
+            Ok(item_id)
+        }
+
+        /// Creator edits a published catalogue item
+        /// The item keeps its id and creation time
+        ///
+        /// Parameters:
+        /// - item_id: Id of the item to edit
+        /// - cid, title, content_type, required_tier: New values
+        ///
+        /// Returns:
+        /// - Ok(()) if the item was updated
+        /// - Err(ContentNotFound) if the caller has no published item with this id
+        /// - Err(InvalidContent) if the CID is empty
+        #[ink(message)]
+        pub fn update_content(
+            &mut self,
+            item_id: u32,
+            cid: String,
+            title: String,
+            content_type: ContentType,
+            required_tier: u32,
+        ) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let mut item = self
+                .content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+
+            if cid.is_empty() {
+                return Err(Error::InvalidContent);
+            }
+
+            item.cid = cid;
+            item.title = title;
+            item.content_type = content_type;
+            item.required_tier = required_tier;
+            self.content_items.insert((creator, item_id), &item);
+
+            Ok(())
+        }
+
+        /// Creator takes an item out of their catalogue
+        /// The item disappears from listings and can no longer be opened; its id
+        /// is never reused
+        ///
+        /// Parameters:
+        /// - item_id: Id of the item to unpublish
+        ///
+        /// Returns:
+        /// - Ok(()) if the item was unpublished
+        /// - Err(ContentNotFound) if the caller has no published item with this id
+        #[ink(message)]
+        pub fn unpublish_content(&mut self, item_id: u32) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            let mut item = self
+                .content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+
+            item.published = false;
+            self.content_items.insert((creator, item_id), &item);
+            self.unindex_content(creator, item_id);
+
+            Ok(())
+        }
+
+        /// Get one page of a creator's published catalogue
+        /// CIDs are left out so anyone can browse; use get_content() to open an item
+        ///
+        /// **Ordering**:
+        /// Items are listed oldest first until one is unpublished - the last
+        /// item then moves into its slot (same swap-remove as get_subscribers).
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        /// - offset: Number of items to skip (0 for the first page)
+        /// - limit: Page size (clamped to MAX_PAGE_SIZE)
+        ///
+        /// Returns:
+        /// - Vector of ContentPreview for this page
+        /// - Total number of published items
+        #[ink(message)]
+        pub fn get_content_list(
+            &self,
+            creator: H160,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<ContentPreview>, u32) {
+            let total = self.published_count.get(creator).unwrap_or(0);
+            let items = Self::page_range(offset, limit, total)
+                .filter_map(|index| self.published_content.get((creator, index)))
+                .filter_map(|item_id| self.content_items.get((creator, item_id)))
+                .map(|item| ContentPreview {
+                    price: self.content_prices.get((creator, item.id)),
+                    ..ContentPreview::from(&item)
+                })
+                .collect();
+
+            (items, total)
+        }
+
+        /// Open a catalogue item (if caller has access)
+        /// Creators can always open their own items; fans need either a live,
+        /// funded subscription on a plan of at least the item's required tier,
+        /// or to have bought the item with purchase_content()
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        /// - item_id: Id of the item
+        ///
+        /// Returns:
+        /// - Ok(ContentItem) including the CID
+        /// - Err(ContentNotFound) if the item doesn't exist or was unpublished
+        ///   (buyers can still open items they bought)
+        /// - Err(SubscriptionRequired) if caller never subscribed
+        /// - Err(SubscriptionExpired) if caller's subscription ran out or was cancelled
+        /// - Err(TierTooLow) if caller's plan tier is below the item's
+        #[ink(message)]
+        pub fn get_content(&self, creator: H160, item_id: u32) -> Result<ContentItem, Error> {
+            let caller: H160 = self.env().caller();
+            let item = self
+                .content_items
+                .get((creator, item_id))
+                .ok_or(Error::ContentNotFound)?;
+
+            let purchased = self.purchases.contains((caller, creator, item_id));
+            if !item.published && !purchased {
+                return Err(Error::ContentNotFound);
+            }
+            if caller != creator && !purchased {
+                self.check_access(caller, creator, item.required_tier)?;
+            }
+
+            Ok(item)
+        }
+
+        /// Creator sets a one-time price on a published item, or takes it off sale
+        /// Subscribers keep opening the item as before; the price only adds a
+        /// way in for fans who'd rather buy it outright
+        ///
+        /// Parameters:
+        /// - item_id: Id of the item
+        /// - price: Price in Planck, or None to make it subscribers-only again
+        ///
+        /// Returns:
+        /// - Ok(()) if the price was updated
+        /// - Err(ContentNotFound) if the caller has no published item with this id
+        /// - Err(InvalidPrice) if the price is zero
+        #[ink(message)]
+        pub fn set_content_price(
+            &mut self,
+            item_id: u32,
+            price: Option<u128>,
+        ) -> Result<(), Error> {
+            let creator: H160 = self.env().caller();
+            self.content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+
+            match price {
+                Some(0) => return Err(Error::InvalidPrice),
+                Some(amount) => {
+                    self.content_prices.insert((creator, item_id), &amount);
+                }
+                None => self.content_prices.remove((creator, item_id)),
+            }
+
+            self.env().emit_event(ContentPriceUpdated {
+                creator,
+                item_id,
+                price,
+            });
+
+            Ok(())
+        }
+
+        /// Fan buys a catalogue item outright
+        /// The fan can open the item forever after, without a subscription.
+        /// The payment is paid out straight away, like a tip.
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        /// - item_id: Id of the item
+        ///
+        /// Payment:
+        /// - Must send exactly the item's price with the transaction
+        ///
+        /// Returns:
+        /// - Ok(()) if the fan now owns the item
+        /// - Err(ContractPaused) while the contract is paused
+        /// - Err(ContentNotFound) if the item doesn't exist or was unpublished
+        /// - Err(NotForSale) if the item has no price
+        /// - Err(AlreadyPurchased) if the fan already bought it
+        /// - Err(InsufficientPayment) / Err(PaymentMismatch) if the payment is
+        ///   below / above the price
+        #[ink(message, payable)]
+        pub fn purchase_content(&mut self, creator: H160, item_id: u32) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let fan: H160 = self.env().caller();
+            let payment: u128 = self
+                .env()
+                .transferred_value()
+                .try_into()
+                .map_err(|_| Error::Overflow)?;
+
+            self.content_items
+                .get((creator, item_id))
+                .filter(|item| item.published)
+                .ok_or(Error::ContentNotFound)?;
+            let price = self
+                .content_prices
+                .get((creator, item_id))
+                .ok_or(Error::NotForSale)?;
+            if self.purchases.contains((fan, creator, item_id)) {
+                return Err(Error::AlreadyPurchased);
+            }
+            if payment < price {
+                return Err(Error::InsufficientPayment);
+            }
+            if payment > price {
+                return Err(Error::PaymentMismatch);
+            }
+
+            self.purchases.insert((fan, creator, item_id), &true);
+            let amount = self.pay_creator(creator, payment)?;
+
+            self.env().emit_event(ContentPurchased {
+                fan,
+                creator,
+                item_id,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Check whether a fan has bought a catalogue item
+        #[ink(message)]
+        pub fn has_purchased(&self, fan: H160, creator: H160, item_id: u32) -> bool {
+            self.purchases.contains((fan, creator, item_id))
+        }
+
+        // 🏛️ PLATFORM FEE FUNCTIONS
+        // The platform operator's cut of creator payouts
+
+        /// Fee manager sets the platform fee taken from every creator payout
+        /// Applies to all payouts from now on, including already vested earnings
+        ///
+        /// Parameters:
+        /// - fee_bps: Fee in basis points (100 = 1%), at most MAX_PLATFORM_FEE_BPS
+        ///
+        /// Returns:
+        /// - Ok(()) if the fee was changed
+        /// - Err(MissingRole) if caller is not the owner or a FeeManager
+        /// - Err(FeeTooHigh) if fee_bps is above MAX_PLATFORM_FEE_BPS
+        #[ink(message)]
+        pub fn set_platform_fee(&mut self, fee_bps: u16) -> Result<(), Error> {
+            self.require_role(Role::FeeManager)?;
+            if fee_bps > MAX_PLATFORM_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+
+            let previous_fee_bps = self.get_platform_fee();
+            self.platform_fee_bps.set(&fee_bps);
+
+            self.env().emit_event(PlatformFeeUpdated {
+                previous_fee_bps,
+                fee_bps,
+            });
+
+            Ok(())
+        }
+
+        /// Owner changes where withdrawn platform fees are sent
+        ///
+        /// Parameters:
+        /// - treasury: Address that will receive future withdrawals
+        ///
+        /// Returns:
+        /// - Ok(()) if the treasury was changed
+        /// - Err(NotOwner) if caller is not the owner
+        #[ink(message)]
+        pub fn set_treasury(&mut self, treasury: H160) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.treasury.set(&treasury);
+
+            self.env().emit_event(TreasuryUpdated { treasury });
+
+            Ok(())
+        }
+
+        /// Fee manager sends all accumulated platform fees to the treasury
+        /// Only the owner can change where the treasury is
+        ///
+        /// Returns:
+        /// - Ok(amount) withdrawn (0 if there was nothing to withdraw)
+        /// - Err(MissingRole) if caller is not the owner or a FeeManager
+        /// - Err(TransferFailed) if the transfer to the treasury failed
+        #[ink(message)]
+        pub fn withdraw_platform_fees(&mut self) -> Result<u128, Error> {
+            self.require_role(Role::FeeManager)?;
+
+            let amount = self.get_platform_balance();
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let treasury = self.get_treasury();
+            self.platform_balance.set(&0);
+            if self.env().transfer(treasury, amount.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            self.env().emit_event(PlatformFeesWithdrawn { treasury, amount });
+
+            Ok(amount)
+        }
+
+        /// Get the current platform fee in basis points
+        #[ink(message)]
+        pub fn get_platform_fee(&self) -> u16 {
+            self.platform_fee_bps.get().unwrap_or(0)
+        }
+
+        /// Get platform fees collected but not yet withdrawn
+        #[ink(message)]
+        pub fn get_platform_balance(&self) -> u128 {
+            self.platform_balance.get().unwrap_or(0)
+        }
+
+        /// Get the address platform fees are withdrawn to (the owner until set)
+        #[ink(message)]
+        pub fn get_treasury(&self) -> H160 {
+            self.treasury.get().unwrap_or_else(|| self.get_owner())
+        }
+
+        // 🛡️ ACCESS CONTROL FUNCTIONS
+        // Ownership and roles for platform administration
+
+        /// Owner nominates a new owner
+        /// Nothing changes until the nominee calls accept_ownership(), so a typo
+        /// in the address can't lock the contract. Nominating again replaces
+        /// the previous nominee.
+        ///
+        /// Parameters:
+        /// - new_owner: Account that will be allowed to accept ownership
+        ///
+        /// Returns:
+        /// - Ok(()) if the nomination was recorded
+        /// - Err(NotOwner) if caller is not the owner
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: H160) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.pending_owner.set(&Some(new_owner));
+
+            self.env().emit_event(OwnershipTransferStarted {
+                owner: self.get_owner(),
+                pending_owner: new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Pending owner accepts ownership, completing the transfer
+        ///
+        /// Returns:
+        /// - Ok(()) if the caller is now the owner
+        /// - Err(NotPendingOwner) if the caller wasn't nominated
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), Error> {
+            let caller: H160 = self.env().caller();
+            if self.get_pending_owner() != Some(caller) {
+                return Err(Error::NotPendingOwner);
+            }
+
+            let previous_owner = self.get_owner();
+            self.owner.set(&caller);
+            self.pending_owner.set(&None);
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Grant a role to an account
+        /// The owner can grant any role; admins can grant Moderator and FeeManager
+        ///
+        /// Parameters:
+        /// - role: Role to grant
+        /// - account: Account receiving the role
+        ///
+        /// Returns:
+        /// - Ok(()) if the account now has the role
+        /// - Err(MissingRole) if caller may not manage this role
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: H160) -> Result<(), Error> {
+            let caller: H160 = self.env().caller();
+            self.ensure_can_manage(caller, role)?;
+
+            self.roles.insert((account, role), &true);
+
+            self.env().emit_event(RoleGranted {
+                account,
+                role,
+                granted_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke a role from an account
+        /// Same permissions as grant_role()
+        ///
+        /// Parameters:
+        /// - role: Role to revoke
+        /// - account: Account losing the role
+        ///
+        /// Returns:
+        /// - Ok(()) if the account no longer has the role
+        /// - Err(MissingRole) if caller may not manage this role
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: Role, account: H160) -> Result<(), Error> {
+            let caller: H160 = self.env().caller();
+            self.ensure_can_manage(caller, role)?;
+
+            self.roles.remove((account, role));
+
+            self.env().emit_event(RoleRevoked {
+                account,
+                role,
+                revoked_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Check whether an account holds a role (the owner holds them all)
+        #[ink(message)]
+        pub fn has_role(&self, role: Role, account: H160) -> bool {
+            account == self.get_owner() || self.roles.contains((account, role))
+        }
+
+        /// Admin pauses the contract in an emergency (e.g., a bug in vesting math)
+        /// While paused nobody can subscribe, top up or claim; fans can still
+        /// cancel to get their unvested balance back
+        ///
+        /// Returns:
+        /// - Ok(()) if the contract is now paused
+        /// - Err(MissingRole) if caller is not the owner or an Admin
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            self.require_role(Role::Admin)?;
+            self.paused.set(&true);
+
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Admin lifts the pause and normal operation resumes
+        ///
+        /// Returns:
+        /// - Ok(()) if the contract is no longer paused
+        /// - Err(MissingRole) if caller is not the owner or an Admin
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Error> {
+            self.require_role(Role::Admin)?;
+            self.paused.set(&false);
+
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Check whether the contract is paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused.get().unwrap_or(false)
+        }
+
+        /// Get the contract owner
+        /// Version 1 had no owner, so after its code is replaced by chain
+        /// governance the contract's own account owns it - only governance can
+        /// act as that account, to run the migration and transfer_ownership()
+        #[ink(message)]
+        pub fn get_owner(&self) -> H160 {
+            self.owner.get().unwrap_or_else(|| self.env().address())
+        }
+
+        /// Get the account nominated to become owner, if any
+        #[ink(message)]
+        pub fn get_pending_owner(&self) -> Option<H160> {
+            self.pending_owner.get().flatten()
+        }
+
+        // 🔄 UPGRADE & MIGRATION FUNCTIONS
+        // Keep creators and subscriptions across code changes
+        //
+        // Upgrade flow: pause() → upgrade(new_code) → migrate_creators() and
+        // migrate_subscriptions() in batches → complete_migration() → unpause()
+        //
+        // Version 1 had no upgrade() or owner: chain governance replaces its
+        // code, then runs the migration as the contract's own account
+
+        /// Owner replaces the contract code, keeping storage and balance
+        /// The new code starts with this storage as-is; if it changes a record
+        /// layout, its migration messages convert the old records
+        ///
+        /// Parameters:
+        /// - code_hash: Hash of code already uploaded to the chain
+        ///
+        /// Returns:
+        /// - Ok(()) if the next call runs the new code
+        /// - Err(NotOwner) if caller is not the owner
+        /// - Err(UpgradeFailed) if the runtime rejected the code hash
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: H256) -> Result<(), Error> {
+            self.ensure_owner()?;
+
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| Error::UpgradeFailed)?;
+
+            self.env().emit_event(Upgraded { code_hash });
+
+            Ok(())
+        }
+
+        /// Get the layout version of the records in storage
+        #[ink(message)]
+        pub fn get_storage_version(&self) -> u32 {
+            self.storage_version.get().unwrap_or(1)
+        }
+
+        /// Owner rewrites version 1 creator profiles in the current layout
+        /// Version 1 counted its creators in creator_count but kept no list, so
+        /// migrated creators fill ids 0..creator_count in migration order.
+        /// Creators already in the current layout (or not registered at all)
+        /// are skipped, so batches can safely overlap
+        ///
+        /// Parameters:
+        /// - creators: Creator addresses to migrate
+        ///
+        /// Returns:
+        /// - Ok(count) with the number of profiles rewritten
+        /// - Err(NotOwner) if caller is not the owner
+        #[ink(message)]
+        pub fn migrate_creators(&mut self, creators: Vec<H160>) -> Result<u32, Error> {
+            self.ensure_owner()?;
+
+            let mut next_id = self.migrated_creator_ids.get().unwrap_or(0);
+            let mut migrated: u32 = 0;
+            for creator in creators {
+                // A record that decodes in the current layout needs no work
+                if !matches!(self.creators.try_get(creator), Some(Err(_))) {
+                    continue;
+                }
+                // Same storage key the Mapping uses: (root key, creator)
+                let key = (self.creators.key(), creator);
+                let Ok(Some(legacy)) =
+                    ink::env::get_contract_storage::<_, CreatorProfileV1>(&key)
+                else {
+                    continue;
+                };
+
+                self.creators.insert(creator, &CreatorProfile::from(legacy));
+                self.creator_ids.insert(next_id, &creator);
+                next_id += 1;
+                migrated += 1;
+            }
+
+            self.migrated_creator_ids.set(&next_id);
+            Ok(migrated)
+        }
+
+        /// Owner rewrites version 1 subscriptions in the current layout
+        /// Each keeps streaming its remaining balance at its old rate, on a
+        /// retired "Legacy" plan created for the creator at that rate. Migrate
+        /// the creator first; pairs already in the current layout are skipped.
+        /// Version 1 left cancelled subscriptions behind as zeroed records -
+        /// those are deleted rather than migrated.
+        ///
+        /// Parameters:
+        /// - pairs: (fan, creator) subscriptions to migrate
+        ///
+        /// Returns:
+        /// - Ok(count) with the number of subscriptions rewritten
+        /// - Err(NotOwner) if caller is not the owner
+        /// - Err(CreatorNotFound) if a creator hasn't been migrated yet
+        #[ink(message)]
+        pub fn migrate_subscriptions(&mut self, pairs: Vec<(H160, H160)>) -> Result<u32, Error> {
+            self.ensure_owner()?;
+
+            let mut migrated: u32 = 0;
+            for (fan, creator) in pairs {
+                if !matches!(self.subscriptions.try_get((fan, creator)), Some(Err(_))) {
+                    continue;
+                }
+                let key = (self.subscriptions.key(), (fan, creator));
+                let Ok(Some(legacy)) = ink::env::get_contract_storage::<_, SubscriptionV1>(&key)
+                else {
+                    continue;
+                };
+
+                if legacy.total_deposited == 0 || legacy.rate_per_second == 0 {
+                    ink::env::clear_contract_storage(&key);
+                    continue;
+                }
+
+                let plan_id = self.legacy_plan_id(creator, legacy.price_per_period()?)?;
+                let subscription = legacy.into_current(plan_id)?;
+                self.save_subscription((fan, creator), &subscription)?;
+                self.index_subscription(fan, creator);
+                migrated += 1;
+            }
+
+            Ok(migrated)
+        }
+
+        /// Owner marks the migration as finished, recording STORAGE_VERSION
+        ///
+        /// Returns:
+        /// - Ok(()) if storage is now at the current version
+        /// - Err(NotOwner) if caller is not the owner
+        /// - Err(AlreadyMigrated) if there was nothing to migrate
+        #[ink(message)]
+        pub fn complete_migration(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+            let from_version = self.get_storage_version();
+            if from_version >= STORAGE_VERSION {
+                return Err(Error::AlreadyMigrated);
+            }
+
+            self.storage_version.set(&STORAGE_VERSION);
+
+            self.env().emit_event(StorageMigrated {
+                from_version,
+                to_version: STORAGE_VERSION,
+            });
+
+            Ok(())
+        }
+
+        // 📋 OPTIMIZED QUERY FUNCTIONS
+        // These functions provide efficient data access for the frontend
+
+        /// Get one page of registered creators with their profiles
+        /// This is used by the frontend to display the creator discovery page
+        ///
+        /// **Key Concept: Storage Iteration Challenges**
+        /// In traditional databases, you can easily "SELECT * FROM creators".
+        /// In blockchain storage a Mapping can't be iterated, so register_creator()
+        /// also writes each address into the creator_ids index (id → address).
+        /// Listing walks that index in registration order.
+        ///
+        /// **Gas Considerations**:
+        /// - Large lists can exceed block gas limits, so results are paginated
+        /// - At most MAX_PAGE_SIZE creators are returned per call
+        /// - Frontend should cache results when possible
+        ///
+        /// **Frontend Usage**:
+        /// ```typescript
+        /// const [page, total] = await contract.query.getCreatorList(0, 20);
+        /// // Show `page`, and a "load more" button while offset + 20 < total
+        /// ```
+        ///
+        /// Parameters:
+        /// - offset: Number of creators to skip (0 for the first page)
+        /// - limit: Page size (clamped to MAX_PAGE_SIZE)
+        ///
+        /// Returns:
+        /// - Vector of (AccountId, CreatorProfile) pairs for this page
+        /// - Total number of registered creators
+        #[ink(message)]
+        pub fn get_creator_list(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<(H160, CreatorProfile)>, u32) {
+            let total = self.creator_count;
+            let creators = Self::page_range(offset, limit, total)
+                .filter_map(|id| self.creator_ids.get(id))
+                .filter_map(|address| {
+                    self.creators
+                        .get(address)
+                        .map(|profile| (address, profile))
+                })
+                .collect();
+
+            (creators, total)
+        }
+
+        /// Get one page of a creator's current subscribers
+        /// Powers the "who subscribes to me?" view on the creator dashboard
+        ///
+        /// **Ordering**:
+        /// When a fan cancels, the last subscriber is moved into their slot, so
+        /// the order is stable between cancellations but not chronological.
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        /// - offset: Number of subscribers to skip (0 for the first page)
+        /// - limit: Page size (clamped to MAX_PAGE_SIZE)
+        ///
+        /// Returns:
+        /// - Vector of (fan AccountId, Subscription) pairs for this page
+        /// - Total number of subscribers
+        #[ink(message)]
+        pub fn get_subscribers(
+            &self,
+            creator: H160,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<(H160, Subscription)>, u32) {
+            let total = self.subscriber_count.get(creator).unwrap_or(0);
+            let subscribers = Self::page_range(offset, limit, total)
+                .filter_map(|index| self.subscribers.get((creator, index)))
+                .filter_map(|fan| {
+                    self.subscriptions
+                        .get((fan, creator))
+                        .map(|subscription| (fan, subscription))
+                })
+                .collect();
+
+            (subscribers, total)
+        }
+
+        /// Get one page of the creators a fan is subscribed to
+        /// Powers the "what am I subscribed to?" view on the fan dashboard
+        ///
+        /// Parameters:
+        /// - fan: Wallet address of the fan
+        /// - offset: Number of subscriptions to skip (0 for the first page)
+        /// - limit: Page size (clamped to MAX_PAGE_SIZE)
+        ///
+        /// Returns:
+        /// - Vector of (creator AccountId, Subscription) pairs for this page
+        /// - Total number of live subscriptions for the fan
+        #[ink(message)]
+        pub fn get_fan_subscriptions(
+            &self,
+            fan: H160,
+            offset: u32,
+            limit: u32,
+        ) -> (Vec<(H160, Subscription)>, u32) {
+            let total = self.fan_subscription_count.get(fan).unwrap_or(0);
+            let subscriptions = Self::page_range(offset, limit, total)
+                .filter_map(|index| self.fan_subscriptions.get((fan, index)))
+                .filter_map(|creator| {
+                    self.subscriptions
+                        .get((fan, creator))
+                        .map(|subscription| (creator, subscription))
+                })
+                .collect();
+
+            (subscriptions, total)
+        }
+
+        /// Get a creator's dashboard numbers in one call
+        ///
+        /// Parameters:
+        /// - creator: Wallet address of the creator
+        ///
+        /// Returns:
+        /// - Ok(CreatorStats) if creator exists
+        /// - Err(CreatorNotFound) if creator doesn't exist
+        #[ink(message)]
+        pub fn get_creator_stats(&self, creator: H160) -> Result<CreatorStats, Error> {
+            let profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+
+            Ok(CreatorStats {
+                total_earned: profile.total_earned,
+                subscriber_count: self.subscriber_count.get(creator).unwrap_or(0),
+                published_content: self.published_count.get(creator).unwrap_or(0),
+                tip_count: self.tip_count.get(creator).unwrap_or(0),
+                total_tips: self.total_tips.get(creator).unwrap_or(0),
+            })
+        }
+
+        // 🔧 INTERNAL HELPERS
+        // Shared logic used by several messages - not callable from outside
+
+        /// Index range for one page of a list with `total` entries
+        /// `limit` is clamped to MAX_PAGE_SIZE
+        fn page_range(offset: u32, limit: u32, total: u32) -> core::ops::Range<u32> {
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(total);
+            offset.min(end)..end
+        }
+
+        /// Appends a new (fan, creator) subscription to the creator's subscriber
+        /// list and the fan's subscription list
+        fn index_subscription(&mut self, fan: H160, creator: H160) {
+            let creator_pos = self.subscriber_count.get(creator).unwrap_or(0);
+            self.subscribers.insert((creator, creator_pos), &fan);
+            self.subscriber_count.insert(creator, &(creator_pos + 1));
+
+            let fan_pos = self.fan_subscription_count.get(fan).unwrap_or(0);
+            self.fan_subscriptions.insert((fan, fan_pos), &creator);
+            self.fan_subscription_count.insert(fan, &(fan_pos + 1));
+
+            self.index_positions
+                .insert((fan, creator), &(creator_pos, fan_pos));
+        }
+
+        /// Removes a (fan, creator) subscription from both lists
+        /// Uses swap-remove: the last entry of each list moves into the freed
+        /// slot, so removal costs the same no matter how long the list is
+        fn unindex_subscription(&mut self, fan: H160, creator: H160) {
+            let Some((creator_pos, fan_pos)) = self.index_positions.take((fan, creator)) else {
+                return;
+            };
+
+            // Creator's subscriber list
+            let last = self
+                .subscriber_count
+                .get(creator)
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if creator_pos != last {
+                if let Some(moved_fan) = self.subscribers.get((creator, last)) {
+                    self.subscribers.insert((creator, creator_pos), &moved_fan);
+                    if let Some((_, moved_fan_pos)) =
+                        self.index_positions.get((moved_fan, creator))
+                    {
+                        self.index_positions
+                            .insert((moved_fan, creator), &(creator_pos, moved_fan_pos));
+                    }
+                }
+            }
+            self.subscribers.remove((creator, last));
+            self.subscriber_count.insert(creator, &last);
+
+            // Fan's subscription list
+            let last = self
+                .fan_subscription_count
+                .get(fan)
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if fan_pos != last {
+                if let Some(moved_creator) = self.fan_subscriptions.get((fan, last)) {
+                    self.fan_subscriptions.insert((fan, fan_pos), &moved_creator);
+                    if let Some((moved_creator_pos, _)) =
+                        self.index_positions.get((fan, moved_creator))
+                    {
+                        self.index_positions
+                            .insert((fan, moved_creator), &(moved_creator_pos, fan_pos));
+                    }
+                }
+            }
+            self.fan_subscriptions.remove((fan, last));
+            self.fan_subscription_count.insert(fan, &last);
+        }
+
+        /// Checks that `fan` may see `creator`'s content requiring `required_tier`
+        ///
+        /// Access needs a live subscription whose deposit hasn't run out yet, on a
+        /// plan of at least the required tier. A fan with only archived
+        /// subscriptions (or a dry one) gets SubscriptionExpired rather than
+        /// SubscriptionRequired so the frontend can offer to renew.
+        fn check_access(&self, fan: H160, creator: H160, required_tier: u32) -> Result<(), Error> {
+            let Some(subscription) = self.subscriptions.get((fan, creator)) else {
+                if self.history_count.get((fan, creator)).unwrap_or(0) > 0 {
+                    return Err(Error::SubscriptionExpired);
+                }
+                return Err(Error::SubscriptionRequired);
+            };
+
+            if subscription.paid_until() <= self.env().block_timestamp() {
+                return Err(Error::SubscriptionExpired);
+            }
+
+            let plan = self
+                .plans
+                .get((creator, subscription.plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            if plan.tier < required_tier {
+                return Err(Error::TierTooLow);
+            }
+
+            Ok(())
+        }
+
+        /// Removes an item from a creator's published list (swap-remove)
+        fn unindex_content(&mut self, creator: H160, item_id: u32) {
+            let Some(position) = self.content_positions.take((creator, item_id)) else {
+                return;
+            };
+
+            let last = self
+                .published_count
+                .get(creator)
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if position != last {
+                if let Some(moved_id) = self.published_content.get((creator, last)) {
+                    self.published_content.insert((creator, position), &moved_id);
+                    self.content_positions.insert((creator, moved_id), &position);
+                }
+            }
+            self.published_content.remove((creator, last));
+            self.published_count.insert(creator, &last);
+        }
+
+        /// Stores a subscription after checking its ledger still balances
+        /// All writes to `subscriptions` go through here
+        fn save_subscription(
+            &mut self,
+            subscription_key: (H160, H160),
+            subscription: &Subscription,
+        ) -> Result<(), Error> {
+            subscription.check_ledger()?;
+            self.subscriptions.insert(subscription_key, subscription);
+            Ok(())
+        }
+
+        /// Sends claimed earnings to a creator and updates their running total
+        /// The platform fee is taken here, so every payout path pays it
+        /// Returns the amount sent to the creator
+        fn pay_creator(&mut self, creator: H160, amount: u128) -> Result<u128, Error> {
+            // Fee rounds down in the creator's favour
+            let fee = amount
+                .checked_mul(u128::from(self.get_platform_fee()))
+                .ok_or(Error::Overflow)?
+                / BPS_DENOMINATOR;
+            let payout = amount - fee;
+            let platform_balance = self
+                .get_platform_balance()
+                .checked_add(fee)
+                .ok_or(Error::Overflow)?;
+            self.platform_balance.set(&platform_balance);
+
+            // Update creator's total earnings for display purposes
+            // This is a running total of all earnings across all fans
+            if let Some(mut profile) = self.creators.get(creator) {
+                profile.total_earned = profile.total_earned.saturating_add(payout);
+                self.creators.insert(creator, &profile);
+            }
+
+            // Transfer the claimed DOT from contract to creator's wallet
+            // (or their collaborators) - moving tokens on the blockchain
+            self.distribute(creator, payout)?;
+
+            Ok(payout)
+        }
+
+        /// Sends a creator's payout to the payees of their revenue split
+        /// Each payee gets their share rounded down and the first payee also
+        /// gets the rounding remainder, so the whole payout is always sent
+        fn distribute(&mut self, creator: H160, payout: u128) -> Result<(), Error> {
+            if payout == 0 {
+                return Ok(());
+            }
+
+            let Some(payees) = self.revenue_splits.get(creator) else {
+                return self.send_or_credit(creator, payout);
+            };
+
+            let amounts = Self::split_amounts(payout, &payees)?;
+            for (share, amount) in payees.iter().zip(amounts) {
+                self.send_or_credit(share.payee, amount)?;
+            }
+
+            Ok(())
+        }
+
+        /// Transfers `amount` to `payee`, or owes it to them in `payee_balances`
+        /// if the transfer fails, so the caller's claim, cancel, tip or purchase
+        /// goes through regardless
+        fn send_or_credit(&mut self, payee: H160, amount: u128) -> Result<(), Error> {
+            if amount == 0 || self.env().transfer(payee, amount.into()).is_ok() {
+                return Ok(());
+            }
+
+            let owed = self.payee_balances.get(payee).unwrap_or(0);
+            let owed = owed.checked_add(amount).ok_or(Error::Overflow)?;
+            self.payee_balances.insert(payee, &owed);
+            Ok(())
+        }
+
+        /// Divides `payout` between `payees` by share, rounding each share down
+        /// and adding the remainder to the first payee's amount
+        fn split_amounts(payout: u128, payees: &[RevenueShare]) -> Result<Vec<u128>, Error> {
+            let mut amounts = Vec::with_capacity(payees.len());
+            let mut allocated: u128 = 0;
+            for share in payees {
+                let amount = payout
+                    .checked_mul(u128::from(share.share_bps))
+                    .ok_or(Error::Overflow)?
+                    / BPS_DENOMINATOR;
+                allocated += amount;
+                amounts.push(amount);
+            }
+            if let Some(first) = amounts.first_mut() {
+                *first += payout - allocated;
+            }
+            Ok(amounts)
+        }
+
+        /// Id of the retired plan that holds `creator`'s migrated subscriptions
+        /// paying `price` per 30 days
+        /// Created on first use for each rate, inactive so nobody new can join it
+        fn legacy_plan_id(&mut self, creator: H160, price: u128) -> Result<u32, Error> {
+            if let Some(plan_id) = self.legacy_plans.get((creator, price)) {
+                return Ok(plan_id);
+            }
+
+            let mut profile = match self.creators.try_get(creator) {
+                Some(Ok(profile)) => profile,
+                _ => return Err(Error::CreatorNotFound),
+            };
+
+            let plan_id = profile.plan_count;
+            self.plans.insert(
+                (creator, plan_id),
+                &SubscriptionPlan {
+                    name: String::from("Legacy"),
+                    price,
+                    period: BillingPeriod::Days(30),
+                    perks: Vec::new(),
+                    active: false,
+                    tier: 0,
+                    min_commitment_periods: 0,
+                },
+            );
+            profile.plan_count += 1;
+            self.creators.insert(creator, &profile);
+            self.legacy_plans.insert((creator, price), &plan_id);
+
+            Ok(plan_id)
+        }
+
+        /// Loads an open pool run by the caller
+        fn sponsor_pool(&self, pool_id: u32) -> Result<SponsorshipPool, Error> {
+            let pool = self.pools.get(pool_id).ok_or(Error::PoolNotFound)?;
+            if pool.sponsor != self.env().caller() {
+                return Err(Error::NotSponsor);
+            }
+            if !pool.active {
+                return Err(Error::PoolClosed);
+            }
+            Ok(pool)
+        }
+
+        /// Keccak-256 hash of a coupon or invite code
+        fn hash_code(code: &str) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Keccak256>(code.as_bytes(), &mut output);
+            output
+        }
+
+        /// Price of one period of a plan costing `price` after a coupon
+        /// Fails if the coupon is unknown, expired, used up or would make it free
+        fn coupon_price(
+            &self,
+            creator: H160,
+            code_hash: [u8; 32],
+            price: u128,
+        ) -> Result<u128, Error> {
+            let coupon = self
+                .coupons
+                .get((creator, code_hash))
+                .ok_or(Error::CouponNotFound)?;
+
+            if coupon
+                .expires_at
+                .is_some_and(|expires_at| self.env().block_timestamp() >= expires_at)
+            {
+                return Err(Error::CouponExpired);
+            }
+            if coupon.max_redemptions > 0 && coupon.redemptions >= coupon.max_redemptions {
+                return Err(Error::CouponExhausted);
+            }
+
+            coupon.discount.apply(price).ok_or(Error::InvalidCoupon)
+        }
+
+        /// Counts one use of a coupon and returns the new redemption count
+        fn redeem_coupon(&mut self, creator: H160, code_hash: [u8; 32]) -> Result<u32, Error> {
+            let mut coupon = self
+                .coupons
+                .get((creator, code_hash))
+                .ok_or(Error::CouponNotFound)?;
+            coupon.redemptions = coupon.redemptions.checked_add(1).ok_or(Error::Overflow)?;
+            self.coupons.insert((creator, code_hash), &coupon);
+            Ok(coupon.redemptions)
+        }
+
+        /// Fails with NotOwner unless the caller is the contract owner
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.get_owner() {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        /// Fails with MissingRole unless the caller holds `role` (or is the owner)
+        /// Guard for privileged messages: `self.require_role(Role::Admin)?;`
+        fn require_role(&self, role: Role) -> Result<(), Error> {
+            if !self.has_role(role, self.env().caller()) {
+                return Err(Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// Fails with ContractPaused while the emergency pause is on
+        fn ensure_not_paused(&self) -> Result<(), Error> {
+            if self.is_paused() {
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
+
+        /// Fails with MissingRole unless `caller` may grant or revoke `role`
+        /// Only the owner manages admins; admins manage every other role
+        fn ensure_can_manage(&self, caller: H160, role: Role) -> Result<(), Error> {
+            let allowed = match role {
+                Role::Admin => caller == self.get_owner(),
+                Role::Moderator | Role::FeeManager => self.has_role(Role::Admin, caller),
+            };
+            if !allowed {
+                return Err(Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// Settles the subscriptions at `range` in the creator's subscriber list
+        /// and pays the aggregate in a single transfer
+        /// Returns (amount paid after the platform fee, offset where the next batch starts)
+        fn claim_range(
+            &mut self,
+            creator: H160,
+            range: core::ops::Range<u32>,
+        ) -> Result<(u128, u32), Error> {
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+
+            let end = range.end;
+            let now = self.env().block_timestamp();
+
+            let mut claimed: u128 = 0;
+            let mut settled: u32 = 0;
+            for index in range {
+                let Some(fan) = self.subscribers.get((creator, index)) else {
+                    continue;
+                };
+                let subscription_key = (fan, creator);
+                let Some(mut subscription) = self.subscriptions.get(subscription_key) else {
+                    continue;
+                };
+
+                subscription.settle(now);
+                let claimable_amount = subscription.claimable();
+                if claimable_amount == 0 {
+                    continue;
+                }
+                subscription.total_claimed += claimable_amount;
+                self.save_subscription(subscription_key, &subscription)?;
+
+                claimed = claimed
+                    .checked_add(claimable_amount)
+                    .ok_or(Error::Overflow)?;
+                settled += 1;
+            }
+
+            if claimed == 0 {
+                return Ok((0, end));
+            }
+
+            let paid = self.pay_creator(creator, claimed)?;
+            self.env().emit_event(BatchEarningsClaimed {
+                creator,
+                amount: paid,
+                subscriptions: settled,
+            });
+
+            Ok((paid, end))
+        }
+
+        /// Opens a subscription for `fan` on a creator's plan, funded with
+        /// `payment_u128` (DOT sent with the call, or drawn from a sponsorship
+        /// pool) - shared by subscribe(), gift_subscription() and
+        /// claim_sponsorship(). `payer` is recorded when it isn't the fan.
+        /// See subscribe() for the rules; returns the deposit
+        fn open_subscription(
+            &mut self,
+            fan: H160,
+            creator: H160,
+            plan_id: u32,
+            coupon: Option<String>,
+            payment_u128: u128,
+            payer: Option<H160>,
+        ) -> Result<u128, Error> {
+            // Verify the creator exists
+            if self.creators.get(creator).is_none() {
+                return Err(Error::CreatorNotFound);
+            }
+
+            // Look up the plan the fan picked - the creator sets the price, not the fan
+            let plan = self
+                .plans
+                .get((creator, plan_id))
+                .ok_or(Error::PlanNotFound)?;
+            if !plan.active {
+                return Err(Error::PlanInactive);
+            }
+
+            // Get current timestamp for subscription start
+            let now = self.env().block_timestamp();
+
+            // Check if fan is already subscribed to this creator
+            // A live subscription must be extended with top_up() instead, but a
+            // depleted one is settled, archived and replaced by the new one
+            let subscription_key = (fan, creator);
+            if let Some(existing) = self.subscriptions.get(subscription_key) {
+                if existing.paid_until() > now {
+                    return Err(Error::SubscriptionAlreadyExists);
+                }
+                self.close_subscription(fan, creator, SubscriptionEnd::Depleted)?;
+            }
+
+            // A coupon lowers the price of every period of this subscription
+            let code_hash = coupon.as_deref().map(Self::hash_code);
+            let price = match code_hash {
+                Some(code_hash) => self.coupon_price(creator, code_hash, plan.price)?,
+                None => plan.price,
+            };
+
+            // Verify the payment matches the plan
+            // Fan should send at least one period (or the whole commitment, if
+            // longer), and only whole periods
+            let committed = price
+                .checked_mul(u128::from(plan.min_commitment_periods))
+                .ok_or(Error::Overflow)?;
+            if payment_u128 < price.max(committed) {
+                return Err(Error::InsufficientPayment);
+            }
+            if payment_u128 % price != 0 {
+                return Err(Error::PaymentMismatch);
+            }
+
+            // A fan's first subscription to a creator gets the plan's free trial:
+            // vesting is settled up to the trial's end, so streaming starts then
+            let trial_days = self.plan_trials.get((creator, plan_id)).unwrap_or(0);
+            let mut trial_ends_at = None;
+            if trial_days > 0 && !self.trials_used.contains(subscription_key) {
+                let trial_ms = BillingPeriod::Days(trial_days).duration_ms();
+                trial_ends_at = Some(now.saturating_add(trial_ms));
+                self.trials_used.insert(subscription_key, &true);
+            }
+
+            // Create subscription record
+            // Streaming rate is the exact fraction price / plan.period
+            let subscription = Subscription {
+                plan_id,
+                total_deposited: payment_u128,
+                total_vested: 0,
+                total_claimed: 0,
+                total_refunded: 0,
+                price_per_period: price,
+                billing_period: plan.period,
+                committed,
+                vesting_remainder: 0,
+                settled_until: trial_ends_at.unwrap_or(now), // Streaming starts after any trial
+                start_time: now,
+                payer,
+            };
+
+            // Store subscription in mapping and list it for both parties
+            self.save_subscription(subscription_key, &subscription)?;
+            self.index_subscription(fan, creator);
+
+            self.env().emit_event(SubscriptionCreated {
+                fan,
+                creator,
+                plan_id,
+                total_deposited: payment_u128,
+                trial_ends_at,
+            });
+
+            if let Some(code_hash) = code_hash {
+                let redemptions = self.redeem_coupon(creator, code_hash)?;
+                self.env().emit_event(CouponRedeemed {
+                    fan,
+                    creator,
+                    code_hash,
+                    plan_id,
+                    price_per_period: price,
+                    redemptions,
+                });
+            }
+
+            Ok(payment_u128)
+        }
+
+        /// Closes the live (fan, creator) subscription
+        ///
+        /// The subscription is settled (vesting any unmet minimum commitment), the
+        /// creator is paid anything vested but unclaimed, and the fan is refunded
+        /// the unvested balance - so its
+        /// outstanding balance is zero when it leaves storage. A compact
+        /// ArchivedSubscription is appended to the pair's history and the full
+        /// record is removed, which refunds its storage deposit.
+        ///
+        /// Returns the amount refunded to the fan (or to the payer of a gift)
+        fn close_subscription(
+            &mut self,
+            fan: H160,
+            creator: H160,
+            reason: SubscriptionEnd,
+        ) -> Result<u128, Error> {
+            let subscription_key = (fan, creator);
+            let mut subscription = self
+                .subscriptions
+                .take(subscription_key)
+                .ok_or(Error::SubscriptionNotFound)?;
+
+            let now = self.env().block_timestamp();
+            subscription.settle(now);
+
+            // Closing early still pays out the fan's minimum commitment - unless
+            // the fan is still in their free trial (streaming hasn't started)
+            if subscription.settled_until <= now {
+                let shortfall = subscription
+                    .committed
+                    .saturating_sub(subscription.total_vested)
+                    .min(subscription.unvested());
+                subscription.total_vested += shortfall;
+            }
+
+            // Creator keeps everything that vested, fan gets back the rest
+            let earned = subscription.claimable();
+            let refund_amount = subscription.unvested();
+            subscription.total_claimed += earned;
+            subscription.total_refunded += refund_amount;
+            subscription.check_ledger()?;
+
+            self.unindex_subscription(fan, creator);
+
+            let index = self.history_count.get(subscription_key).unwrap_or(0);
+            let archived = ArchivedSubscription {
+                plan_id: subscription.plan_id,
+                start_time: subscription.start_time,
+                ended_at: now,
+                total_deposited: subscription.total_deposited,
+                total_claimed: subscription.total_claimed,
+                refunded: subscription.total_refunded,
+                reason,
+            };
+            self.subscription_history
+                .insert((fan, creator, index), &archived);
+            self.history_count
+                .insert(subscription_key, &index.saturating_add(1));
+
+            if earned > 0 && self.is_paused() {
+                // No payouts during a pause - the fan leaves without waiting on
+                // the creator's transfers, and the creator claims after unpause
+                let held = self.held_earnings.get(creator).unwrap_or(0);
+                let held = held.checked_add(earned).ok_or(Error::Overflow)?;
+                self.held_earnings.insert(creator, &held);
+            } else if earned > 0 {
+                let paid = self.pay_creator(creator, earned)?;
+                self.env().emit_event(EarningsClaimed {
+                    creator,
+                    fan,
+                    amount: paid,
+                });
+            }
+
+            // Unused DOT of a sponsored seat goes back into its pool, or to the
+            // sponsor if the pool has been closed meanwhile
+            let mut refund_to = subscription.payer.unwrap_or(fan);
+            if let Some(pool_id) = self.sponsored_by.take(subscription_key) {
+                if let Some(mut pool) = self.pools.get(pool_id) {
+                    if pool.active {
+                        pool.balance = pool
+                            .balance
+                            .checked_add(refund_amount)
+                            .ok_or(Error::Overflow)?;
+                        self.pools.insert(pool_id, &pool);
+                        return Ok(refund_amount);
+                    }
+                    refund_to = pool.sponsor;
+                }
+            }
+
+            // Transfer refund to fan (or whoever paid for a gift) if there's
+            // anything to refund - only attempt transfer if refund_amount > 0 to save gas
+            if refund_amount > 0 && self.env().transfer(refund_to, refund_amount.into()).is_err() {
+                return Err(Error::TransferFailed);
+            }
+
+            Ok(refund_amount)
+        }
+    }
+
+    // 🧪 UNIT TESTS
+    // These tests verify our contract logic works correctly
+    // Run with: cargo test
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Helper: the caller (already a registered creator) adds a 30-day plan
+        fn create_monthly_plan(contract: &mut CreatorTreasuryPop, price: u128) -> u32 {
+            contract
+                .create_plan("Supporter".to_string(), price, BillingPeriod::Monthly, Vec::new())
+                .unwrap()
+        }
+
+        /// Helper: an account's balance in the off-chain environment
+        fn balance_of(account: H160) -> u128 {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .unwrap()
+                .try_into()
+                .unwrap()
+        }
+
+        /// Test that creators can register successfully
+        #[ink::test]
+        fn test_creator_registration() {
+            let mut contract = CreatorTreasuryPop::new();
+
+            // Register a creator
+            let result = contract.register_creator("Alice".to_string());
+            assert!(result.is_ok());
+
+            // Verify creator count increased
+            assert_eq!(contract.get_creator_count(), 1);
+
+            // Verify creator exists
+            let alice = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice;
+            assert!(contract.is_creator(alice));
+        }
+
+        /// Test that duplicate registration fails
+        #[ink::test]
+        fn test_duplicate_registration_fails() {
+            let mut contract = CreatorTreasuryPop::new();
+
+            // Register creator once
+            let result1 = contract.register_creator("Alice".to_string());
+            assert!(result1.is_ok());
+
+            // Try to register again - should fail
+            let result2 = contract.register_creator("Alice Again".to_string());
+            assert_eq!(result2, Err(Error::CreatorAlreadyExists));
+        }
+
+        /// Test subscription creation
+        #[ink::test]
+        fn test_subscription_creation() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Set up: Alice is creator, Bob is fan
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+
+            // Bob subscribes to Alice
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            ); // 5 DOT
+
+            let result = contract.subscribe(accounts.alice, plan_id, None);
+            assert!(result.is_ok());
+
+            // Verify subscription exists
+            let subscription = contract.get_subscription(accounts.bob, accounts.alice);
+            assert!(subscription.is_ok());
+        }
+
+        /// Test earnings claiming with time-based vesting
+        #[ink::test]
+        fn test_claim_earnings() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Set up subscription
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            );
+            contract
+                .subscribe(accounts.alice, plan_id, None)
+                .unwrap();
+
+            // Simulate time passing (advance block timestamp)
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 3600000,
+            ); // +1 hour
+
+            // Alice claims earnings
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let result = contract.claim_earnings(accounts.bob);
+            assert!(result.is_ok());
+
+            let claimed_amount = result.unwrap();
+            assert!(claimed_amount > 0); // Should have vested some amount
+        }
+
+        /// Test subscription cancellation with refunds
+        #[ink::test]
+        fn test_cancel_subscription() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Set up subscription
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            );
+            contract
+                .subscribe(accounts.alice, plan_id, None)
+                .unwrap();
+
+            // Simulate some time passing
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 1800000,
+            ); // +30 minutes
+
+            // Bob cancels subscription
+            let result = contract.cancel_subscription(accounts.alice);
+            assert!(result.is_ok());
+
+            let refund_amount = result.unwrap();
+            assert!(refund_amount > 0); // Should get partial refund
+
+            // Verify subscription is removed
+            let subscription = contract.get_subscription(accounts.bob, accounts.alice);
+            assert!(subscription.is_err());
+        }
+
+        /// Test content management and gating
+        #[ink::test]
+        fn test_content_gating() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Alice registers as creator and adds content
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+
+            let content_hash = "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u".to_string();
+            let result = contract.add_exclusive_content(content_hash.clone());
+            assert!(result.is_ok());
+
+            // Bob tries to access content without subscription - should fail
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let content_result = contract.get_creator_content(accounts.alice);
+            assert_eq!(content_result, Err(Error::SubscriptionRequired));
+
+            // Bob subscribes to Alice
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            );
+            contract
+                .subscribe(accounts.alice, plan_id, None)
+                .unwrap();
+
+            // Now Bob can access content
+            let content_result = contract.get_creator_content(accounts.alice);
+            assert!(content_result.is_ok());
+            assert_eq!(content_result.unwrap(), content_hash);
+        }
+
+        /// Test content access ends with the subscription and respects plan tiers
+        #[ink::test]
+        fn test_content_requires_live_subscription() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let supporter = create_monthly_plan(&mut contract, monthly_rate);
+            let patron = create_monthly_plan(&mut contract, monthly_rate * 10);
+            contract.set_plan_tier(patron, 2).unwrap();
+            assert_eq!(contract.get_plan(accounts.alice, patron).unwrap().tier, 2);
+            contract.add_exclusive_content("QmContent".to_string()).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, supporter, None).unwrap();
+
+            // Supporter (tier 0) is below tier 2 content
+            assert_eq!(
+                contract.check_access(accounts.bob, accounts.alice, 2),
+                Err(Error::TierTooLow)
+            );
+            assert_eq!(contract.check_access(accounts.bob, accounts.alice, 0), Ok(()));
+
+            // Once the deposit has fully streamed, access expires
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 30 * 24 * 60 * 60 * 1000,
+            );
+            let content_result = contract.get_creator_content(accounts.alice);
+            assert_eq!(content_result, Err(Error::SubscriptionExpired));
+
+            // Upgrading to Patron restores access, including tier 2 content
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                monthly_rate * 10,
+            );
+            contract.subscribe(accounts.alice, patron, None).unwrap();
+            assert_eq!(contract.check_access(accounts.bob, accounts.alice, 2), Ok(()));
+            assert_eq!(
+                contract.get_creator_content(accounts.alice),
+                Ok("QmContent".to_string())
+            );
+
+            // Cancelling ends access - the fan is told to renew, not to subscribe
+            contract.cancel_subscription(accounts.alice).unwrap();
+            let content_result = contract.get_creator_content(accounts.alice);
+            assert_eq!(content_result, Err(Error::SubscriptionExpired));
+
+            // Charlie never subscribed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let content_result = contract.get_creator_content(accounts.alice);
+            assert_eq!(content_result, Err(Error::SubscriptionRequired));
+        }
+
+        /// Test the content catalogue: add, list, open, update and unpublish
+        #[ink::test]
+        fn test_content_catalogue() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            let intro = contract
+                .add_content("QmIntro".to_string(), "Intro".to_string(), ContentType::Video, 0)
+                .unwrap();
+            let premium = contract
+                .add_content(
+                    "QmPremium".to_string(),
+                    "Deep dive".to_string(),
+                    ContentType::Article,
+                    1,
+                )
+                .unwrap();
+            let extra = contract
+                .add_content("QmExtra".to_string(), "Extras".to_string(), ContentType::Download, 0)
+                .unwrap();
+            assert_eq!((intro, premium, extra), (0, 1, 2));
+
+            let result =
+                contract.add_content(String::new(), "Empty".to_string(), ContentType::Image, 0);
+            assert_eq!(result, Err(Error::InvalidContent));
+
+            // Creator can always open their own items
+            assert_eq!(contract.get_content(accounts.alice, premium).unwrap().cid, "QmPremium");
+
+            // Anyone can browse the catalogue without seeing CIDs
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let (items, total) = contract.get_content_list(accounts.alice, 0, 10);
+            assert_eq!(total, 3);
+            assert_eq!(items[1].title, "Deep dive".to_string());
+            assert_eq!(items[1].required_tier, 1);
+
+            // Opening an item needs a subscription on a high enough tier
+            assert_eq!(
+                contract.get_content(accounts.alice, intro),
+                Err(Error::SubscriptionRequired)
+            );
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            assert_eq!(contract.get_content(accounts.alice, intro).unwrap().cid, "QmIntro");
+            assert_eq!(
+                contract.get_content(accounts.alice, premium),
+                Err(Error::TierTooLow)
+            );
+
+            // Alice lowers the tier and swaps the file
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract
+                .update_content(
+                    premium,
+                    "QmPremiumV2".to_string(),
+                    "Deep dive".to_string(),
+                    ContentType::Article,
+                    0,
+                )
+                .unwrap();
+
+            // Unpublishing the intro moves the last item into its slot
+            contract.unpublish_content(intro).unwrap();
+            assert_eq!(contract.unpublish_content(intro), Err(Error::ContentNotFound));
+            let (items, total) = contract.get_content_list(accounts.alice, 0, 10);
+            assert_eq!(total, 2);
+            assert_eq!(items[0].id, extra);
+            assert_eq!(items[1].id, premium);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.get_content(accounts.alice, premium).unwrap().cid,
+                "QmPremiumV2"
+            );
+            assert_eq!(
+                contract.get_content(accounts.alice, intro),
+                Err(Error::ContentNotFound)
+            );
+        }
+
+        /// Test the platform fee is taken from payouts and withdrawn to the treasury
+        #[ink::test]
+        fn test_platform_fee() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Django deploys and owns the contract
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut contract = CreatorTreasuryPop::new();
+            assert_eq!(contract.get_owner(), accounts.django);
+            assert_eq!(contract.get_treasury(), accounts.django);
+            assert_eq!(contract.get_platform_fee(), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            // Only the owner or a fee manager configures the fee, never above the cap
+            assert_eq!(contract.set_platform_fee(500), Err(Error::MissingRole));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                contract.set_platform_fee(MAX_PLATFORM_FEE_BPS + 1),
+                Err(Error::FeeTooHigh)
+            );
+            contract.set_platform_fee(500).unwrap(); // 5%
+            contract.set_treasury(accounts.eve).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 10 * 24 * 60 * 60 * 1000,
+            );
+
+            // A third has vested; Alice receives 95% of it
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let vested = monthly_rate / 3;
+            let paid = contract.claim_earnings(accounts.bob).unwrap();
+            assert_eq!(paid, vested - vested / 20);
+            assert_eq!(contract.get_platform_balance(), vested / 20);
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, paid);
+
+            // Withdrawals go to the treasury and empty the platform balance
+            assert_eq!(contract.withdraw_platform_fees(), Err(Error::MissingRole));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.withdraw_platform_fees(), Ok(vested / 20));
+            assert_eq!(contract.get_platform_balance(), 0);
+            assert_eq!(contract.withdraw_platform_fees(), Ok(0));
+        }
+
+        /// Test revenue splits are validated and divide payouts exactly
+        #[ink::test]
+        fn test_revenue_split() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = CreatorTreasuryPop::new();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let share = |payee, share_bps| RevenueShare { payee, share_bps };
+            let split = vec![share(accounts.alice, 5_000), share(accounts.charlie, 5_000)];
+            assert_eq!(contract.set_revenue_split(split.clone()), Err(Error::CreatorNotFound));
+            contract.register_creator("Band".to_string()).unwrap();
+
+            // Shares must be non-zero, distinct and add up to exactly 100%
+            let short = vec![share(accounts.alice, 5_000), share(accounts.charlie, 4_000)];
+            assert_eq!(contract.set_revenue_split(short), Err(Error::InvalidSplit));
+            let twice = vec![share(accounts.charlie, 5_000), share(accounts.charlie, 5_000)];
+            assert_eq!(contract.set_revenue_split(twice), Err(Error::InvalidSplit));
+            let zero = vec![share(accounts.alice, 10_000), share(accounts.charlie, 0)];
+            assert_eq!(contract.set_revenue_split(zero), Err(Error::InvalidSplit));
+            let crowd = vec![share(accounts.charlie, 1); MAX_SPLIT_PAYEES as usize + 1];
+            assert_eq!(contract.set_revenue_split(crowd), Err(Error::TooManyPayees));
+
+            contract.set_revenue_split(split.clone()).unwrap();
+            assert_eq!(contract.get_revenue_split(accounts.alice), split);
+
+            // Rounding dust goes to the first payee, so nothing is lost
+            let trio = vec![
+                share(accounts.alice, 3_334),
+                share(accounts.charlie, 3_333),
+                share(accounts.django, 3_333),
+            ];
+            let amounts = CreatorTreasuryPop::split_amounts(1_000, &trio).unwrap();
+            assert_eq!(amounts, vec![334, 333, 333]);
+            let amounts = CreatorTreasuryPop::split_amounts(10, &trio).unwrap();
+            assert_eq!(amounts, vec![4, 3, 3]);
+
+            // Claims still report the whole payout and credit the creator's total
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 10 * 24 * 60 * 60 * 1000,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let paid = contract.claim_earnings(accounts.bob).unwrap();
+            assert_eq!(paid, monthly_rate / 3);
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, paid);
+
+            // An empty split pays the creator everything again
+            contract.set_revenue_split(Vec::new()).unwrap();
+            assert!(contract.get_revenue_split(accounts.alice).is_empty());
+        }
+
+        /// Test a payee whose transfer fails is owed their share, without
+        /// failing the claim, and can withdraw it later
+        #[ink::test]
+        fn test_failed_payee_transfer() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = CreatorTreasuryPop::new();
+            let contract_account = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Band".to_string()).unwrap();
+            let share = |payee, share_bps| RevenueShare { payee, share_bps };
+            let split = vec![share(accounts.alice, 5_000), share(accounts.charlie, 5_000)];
+            contract.set_revenue_split(split).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 10 * 24 * 60 * 60 * 1000,
+            );
+
+            // The contract only holds enough for Alice's half, so Charlie's
+            // transfer fails - the claim still goes through and Charlie is owed
+            let half = monthly_rate / 6;
+            ink::env::test::set_account_balance(contract_account, half.into());
+            let alice_before = balance_of(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.claim_earnings(accounts.bob), Ok(2 * half));
+            assert_eq!(balance_of(accounts.alice), alice_before + half);
+            assert_eq!(contract.get_payee_balance(accounts.charlie), half);
+            assert_eq!(contract.get_payee_balance(accounts.alice), 0);
+
+            // Once the contract can pay, Charlie withdraws the owed half
+            ink::env::test::set_account_balance(contract_account, half.into());
+            let charlie_before = balance_of(accounts.charlie);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.withdraw_payee_balance(), Ok(half));
+            assert_eq!(balance_of(accounts.charlie), charlie_before + half);
+            assert_eq!(contract.get_payee_balance(accounts.charlie), 0);
+            assert_eq!(contract.withdraw_payee_balance(), Ok(0));
+        }
+
+        /// Test tips pay the creator straight away and show up in their stats
+        #[ink::test]
+        fn test_tips() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = CreatorTreasuryPop::new();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            contract.set_platform_fee(1_000).unwrap(); // 10%
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            let result = contract.tip(accounts.alice, None);
+            assert_eq!(result, Err(Error::InsufficientPayment));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            let result = contract.tip(accounts.charlie, None);
+            assert_eq!(result, Err(Error::CreatorNotFound));
+
+            let note = Some("QmThanks".to_string());
+            assert_eq!(contract.tip(accounts.alice, note), Ok(900));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.tip(accounts.alice, None), Ok(900));
+            assert_eq!(contract.get_platform_balance(), 200);
+
+            // Tips don't open a subscription
+            let result = contract.get_subscription(accounts.charlie, accounts.alice);
+            assert_eq!(result, Err(Error::SubscriptionNotFound));
+
+            let stats = contract.get_creator_stats(accounts.alice).unwrap();
+            assert_eq!(stats.tip_count, 2);
+            assert_eq!(stats.total_tips, 1_800);
+            assert_eq!(stats.total_earned, 1_800);
+            assert_eq!(stats.subscriber_count, 0);
+        }
+
+        /// Test pay-per-view items grant permanent access without a subscription
+        #[ink::test]
+        fn test_content_purchase() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let item_id = contract
+                .add_content("QmTrack".to_string(), "Single".to_string(), ContentType::Audio, 0)
+                .unwrap();
+
+            // Items are subscribers-only until priced
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            let result = contract.purchase_content(accounts.alice, item_id);
+            assert_eq!(result, Err(Error::NotForSale));
+            let result = contract.get_content(accounts.alice, item_id);
+            assert_eq!(result, Err(Error::SubscriptionRequired));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_content_price(item_id, Some(0)), Err(Error::InvalidPrice));
+            contract.set_content_price(item_id, Some(500)).unwrap();
+            let (items, _) = contract.get_content_list(accounts.alice, 0, 10);
+            assert_eq!(items[0].price, Some(500));
+
+            // The exact price buys the item once
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(400);
+            let result = contract.purchase_content(accounts.alice, item_id);
+            assert_eq!(result, Err(Error::InsufficientPayment));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(600);
+            let result = contract.purchase_content(accounts.alice, item_id);
+            assert_eq!(result, Err(Error::PaymentMismatch));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            contract.purchase_content(accounts.alice, item_id).unwrap();
+            let result = contract.purchase_content(accounts.alice, item_id);
+            assert_eq!(result, Err(Error::AlreadyPurchased));
+            assert!(contract.has_purchased(accounts.bob, accounts.alice, item_id));
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, 500);
+
+            // Bob opens it without subscribing, even after it's unpublished
+            assert_eq!(contract.get_content(accounts.alice, item_id).unwrap().cid, "QmTrack");
+            let result = contract.get_creator_item_content(accounts.alice, item_id);
+            assert_eq!(result, Ok("QmTrack".to_string()));
+            let result = contract.get_creator_content(accounts.alice);
+            assert_eq!(result, Err(Error::SubscriptionRequired));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.unpublish_content(item_id).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.get_content(accounts.alice, item_id).is_ok());
+            assert!(contract.get_creator_item_content(accounts.alice, item_id).is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let result = contract.get_content(accounts.alice, item_id);
+            assert_eq!(result, Err(Error::ContentNotFound));
+        }
+
+        /// Test free trials open content without vesting, once per creator
+        #[ink::test]
+        fn test_free_trial() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let day_ms = 24 * 60 * 60 * 1000;
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            contract.add_exclusive_content("QmContent".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+            let result = contract.set_plan_trial(plan_id, MAX_TRIAL_DAYS + 1);
+            assert_eq!(result, Err(Error::InvalidPlan));
+            contract.set_plan_trial(plan_id, 7).unwrap();
+            contract.set_plan_commitment(plan_id, 1).unwrap();
+            assert_eq!(contract.get_plan_trial(accounts.alice, plan_id), 7);
+
+            let start = ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            assert!(contract.has_used_trial(accounts.bob, accounts.alice));
+
+            // Content opens during the trial, but nothing vests
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                start + 6 * day_ms,
+            );
+            let result = contract.get_creator_content(accounts.alice);
+            assert_eq!(result, Ok("QmContent".to_string()));
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.claimable, 0);
+            assert_eq!(overview.paid_until, start + 37 * day_ms);
+
+            // Once the trial ends the deposit streams on its own
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                start + 22 * day_ms,
+            );
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.claimable, monthly_rate / 2);
+
+            // Cancelling during a trial refunds everything, commitment or not
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                start + 25 * day_ms,
+            );
+            assert_eq!(contract.cancel_subscription(accounts.alice), Ok(monthly_rate));
+
+            // No second trial with the same creator
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            let subscription = contract
+                .get_subscription(accounts.charlie, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.settled_until, start + 25 * day_ms);
+        }
+
+        /// Test coupons discount every period and enforce their limits
+        #[ink::test]
+        fn test_coupons() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let code = "LAUNCH25".to_string();
+            let code_hash = contract.hash_coupon_code(code.clone());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 4_000_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            // Discounts must leave something to pay, and codes are unique
+            let result = contract.create_coupon(code_hash, Discount::Percent(100), 0, None);
+            assert_eq!(result, Err(Error::InvalidCoupon));
+            contract
+                .create_coupon(code_hash, Discount::Percent(25), 1, None)
+                .unwrap();
+            let result = contract.create_coupon(code_hash, Discount::Fixed(1), 0, None);
+            assert_eq!(result, Err(Error::InvalidCoupon));
+
+            // Bob pays 75% of the plan price, for every period
+            let discounted = monthly_rate / 4 * 3;
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(discounted);
+            let result = contract.subscribe(accounts.alice, plan_id, Some("WRONG".to_string()));
+            assert_eq!(result, Err(Error::CouponNotFound));
+            contract
+                .subscribe(accounts.alice, plan_id, Some(code.clone()))
+                .unwrap();
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.price_per_period, discounted);
+            assert_eq!(contract.get_coupon(accounts.alice, code_hash).unwrap().redemptions, 1);
+
+            // One redemption allowed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let result = contract.subscribe(accounts.alice, plan_id, Some(code));
+            assert_eq!(result, Err(Error::CouponExhausted));
+
+            // Fixed discounts and expiry
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let now = ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            let flash_hash = contract.hash_coupon_code("FLASH".to_string());
+            let discount = Discount::Fixed(monthly_rate / 2);
+            contract
+                .create_coupon(flash_hash, discount, 0, Some(now + 1_000))
+                .unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(now + 1_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate / 2);
+            let result = contract.subscribe(accounts.alice, plan_id, Some("FLASH".to_string()));
+            assert_eq!(result, Err(Error::CouponExpired));
+
+            // Revoked coupons are gone
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.revoke_coupon(flash_hash).unwrap();
+            let result = contract.get_coupon(accounts.alice, flash_hash);
+            assert_eq!(result, Err(Error::CouponNotFound));
+        }
+
+        /// Test gifted subscriptions belong to the fan but refund the payer
+        #[ink::test]
+        fn test_gift_subscription() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            contract.add_exclusive_content("QmContent".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            // Charlie buys Bob a month
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            let result = contract.gift_subscription(accounts.charlie, accounts.alice, plan_id);
+            assert_eq!(result, Err(Error::InvalidRecipient));
+            contract
+                .gift_subscription(accounts.bob, accounts.alice, plan_id)
+                .unwrap();
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.payer, Some(accounts.charlie));
+
+            // Bob has full access, but can't top up someone else's gift
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.get_creator_content(accounts.alice).is_ok());
+            assert_eq!(contract.top_up(accounts.alice), Err(Error::GiftedSubscription));
+
+            // Bob cancels half way; the refund is Charlie's
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 15 * 24 * 60 * 60 * 1000,
+            );
+            let charlie_before = balance_of(accounts.charlie);
+            let bob_before = balance_of(accounts.bob);
+            let refund = contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(refund, monthly_rate / 2);
+            assert_eq!(balance_of(accounts.charlie), charlie_before + refund);
+            assert_eq!(balance_of(accounts.bob), bob_before);
+
+            // Bob's next subscription is his own
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.payer, None);
+            contract.top_up(accounts.alice).unwrap();
+        }
+
+        /// Test sponsorship pools pay for allowlisted and invited beneficiaries
+        #[ink::test]
+        fn test_sponsorship_pool() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            contract.add_exclusive_content("QmCourse".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            // Charlie funds two seats: one for Bob, one behind an invite code
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            let pool_id = contract.create_pool(accounts.alice, plan_id, 1).unwrap();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            assert_eq!(contract.fund_pool(pool_id), Ok(2 * monthly_rate));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            contract
+                .add_beneficiaries(pool_id, vec![accounts.bob, accounts.frank])
+                .unwrap();
+            assert!(contract.is_beneficiary(pool_id, accounts.bob));
+            let invite_hash = contract.hash_coupon_code("SCHOLAR-1".to_string());
+            let spare_hash = contract.hash_coupon_code("SCHOLAR-2".to_string());
+            contract
+                .add_invites(pool_id, vec![invite_hash, spare_hash])
+                .unwrap();
+            contract.revoke_invite(pool_id, spare_hash).unwrap();
+            assert_eq!(contract.get_pool(pool_id).unwrap().open_invites, 1);
+
+            // Only the sponsor manages the pool
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            let result = contract.add_beneficiaries(pool_id, vec![accounts.eve]);
+            assert_eq!(result, Err(Error::NotSponsor));
+
+            // Eve has neither an allowlist entry nor a live code
+            let result = contract.claim_sponsorship(pool_id, Some("GUESS".to_string()));
+            assert_eq!(result, Err(Error::NotInvited));
+            let result = contract.claim_sponsorship(pool_id, Some("SCHOLAR-2".to_string()));
+            assert_eq!(result, Err(Error::NotInvited));
+
+            // Bob claims from the allowlist, Django with the invite code
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.claim_sponsorship(pool_id, None).unwrap();
+            let result = contract.claim_sponsorship(pool_id, None);
+            assert_eq!(result, Err(Error::SeatAlreadyClaimed));
+            assert!(contract.get_creator_content(accounts.alice).is_ok());
+            assert_eq!(contract.get_sponsoring_pool(accounts.bob, accounts.alice), Some(pool_id));
+            assert_eq!(contract.top_up(accounts.alice), Err(Error::GiftedSubscription));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract
+                .claim_sponsorship(pool_id, Some("SCHOLAR-1".to_string()))
+                .unwrap();
+            assert_eq!(contract.get_pool(pool_id).unwrap().open_invites, 0);
+
+            // The claim revealed Django's code, but it's used up
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            let result = contract.claim_sponsorship(pool_id, Some("SCHOLAR-1".to_string()));
+            assert_eq!(result, Err(Error::NotInvited));
+
+            // Pool is empty - Frank is allowlisted but there's nothing left
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            let result = contract.claim_sponsorship(pool_id, None);
+            assert_eq!(result, Err(Error::PoolExhausted));
+            let pool = contract.get_pool(pool_id).unwrap();
+            assert_eq!(pool.balance, 0);
+            assert_eq!(pool.seats_claimed, 2);
+
+            // Bob drops out half way; his unused half month returns to the pool
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 15 * 24 * 60 * 60 * 1000,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(contract.get_pool(pool_id).unwrap().balance, monthly_rate / 2);
+            assert_eq!(contract.get_sponsoring_pool(accounts.bob, accounts.alice), None);
+
+            // Charlie closes the pool and takes back what's left
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.close_pool(pool_id), Ok(monthly_rate / 2));
+            assert_eq!(contract.close_pool(pool_id), Err(Error::PoolClosed));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            let result = contract.claim_sponsorship(pool_id, None);
+            assert_eq!(result, Err(Error::PoolClosed));
+        }
+
+        /// Test two-step ownership transfer and role management
+        #[ink::test]
+        fn test_ownership_and_roles() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut contract = CreatorTreasuryPop::new();
+            assert!(contract.has_role(Role::Admin, accounts.django));
+
+            // Only the owner hands out admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                contract.grant_role(Role::Admin, accounts.alice),
+                Err(Error::MissingRole)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.grant_role(Role::Admin, accounts.alice).unwrap();
+
+            // Admins manage the other roles, but not admin itself
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.grant_role(Role::FeeManager, accounts.bob).unwrap();
+            assert_eq!(
+                contract.grant_role(Role::Admin, accounts.bob),
+                Err(Error::MissingRole)
+            );
+            assert!(contract.has_role(Role::FeeManager, accounts.bob));
+            assert!(!contract.has_role(Role::Moderator, accounts.bob));
+
+            // The guard lets fee managers through and blocks everyone else
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.set_platform_fee(100).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.revoke_role(Role::FeeManager, accounts.bob).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_platform_fee(200), Err(Error::MissingRole));
+
+            // Ownership only moves once the nominee accepts
+            assert_eq!(
+                contract.transfer_ownership(accounts.bob),
+                Err(Error::NotOwner)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.transfer_ownership(accounts.eve).unwrap();
+            assert_eq!(contract.get_owner(), accounts.django);
+            assert_eq!(contract.get_pending_owner(), Some(accounts.eve));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.accept_ownership(), Err(Error::NotPendingOwner));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            contract.accept_ownership().unwrap();
+            assert_eq!(contract.get_owner(), accounts.eve);
+            assert_eq!(contract.get_pending_owner(), None);
+            assert!(!contract.has_role(Role::Admin, accounts.django));
+        }
+
+        /// Test the emergency pause blocks money flows but not cancellation
+        #[ink::test]
+        fn test_emergency_pause() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut contract = CreatorTreasuryPop::new();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+
+            // Only admins can pull the switch
+            assert_eq!(contract.pause(), Err(Error::MissingRole));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.pause().unwrap();
+            assert!(contract.is_paused());
+
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 15 * 24 * 60 * 60 * 1000,
+            );
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.status, SubscriptionStatus::Paused);
+
+            // No new subscriptions, top-ups or claims
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let result = contract.subscribe(accounts.alice, plan_id, None);
+            assert_eq!(result, Err(Error::ContractPaused));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.top_up(accounts.alice), Err(Error::ContractPaused));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.claim_earnings(accounts.bob), Err(Error::ContractPaused));
+            assert_eq!(contract.claim_all(), Err(Error::ContractPaused));
+            assert_eq!(contract.claim_batch(0, 10), Err(Error::ContractPaused));
+
+            // Bob can still leave with his unvested half; Alice's half is held
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let refund = contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(refund, monthly_rate / 2);
+            assert_eq!(contract.get_held_earnings(accounts.alice), monthly_rate / 2);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.claim_held_earnings(), Err(Error::ContractPaused));
+
+            // Unpausing restores normal operation
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.unpause().unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.claim_held_earnings(), Ok(monthly_rate / 2));
+            assert_eq!(contract.get_held_earnings(accounts.alice), 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+        }
+
+        /// Test a version 1 contract is migrated in place after an upgrade
+        #[ink::test]
+        fn test_storage_migration() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Version 1 code left a root cell holding only creator_count; the
+            // new code must decode it as-is, or every call would trap
+            let root_key = <CreatorTreasuryPop as StorageKey>::KEY;
+            ink::env::set_contract_storage(&root_key, &1u32);
+            let mut contract =
+                ink::env::get_contract_storage::<_, CreatorTreasuryPop>(&root_key)
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(contract.get_storage_version(), 1);
+            assert_eq!(contract.get_creator_count(), 1);
+            assert!(!contract.is_paused());
+
+            // ...plus the version 1 records of one creator, two subscriptions at
+            // different rates and one cancelled (zeroed) subscription
+            let now = ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            let rate_per_second = 1_000_000u128;
+            let deposit = rate_per_second * LEGACY_SECONDS_PER_PERIOD;
+            ink::env::set_contract_storage(
+                &(contract.creators.key(), accounts.alice),
+                &CreatorProfileV1 {
+                    name: "Alice".to_string(),
+                    content_hash: None,
+                    total_earned: 0,
+                    created_at: now,
+                },
+            );
+            ink::env::set_contract_storage(
+                &(contract.subscriptions.key(), (accounts.bob, accounts.alice)),
+                &SubscriptionV1 {
+                    total_deposited: deposit,
+                    rate_per_second,
+                    last_claim_time: now,
+                    start_time: now,
+                },
+            );
+            ink::env::set_contract_storage(
+                &(contract.subscriptions.key(), (accounts.frank, accounts.alice)),
+                &SubscriptionV1 {
+                    total_deposited: 2 * deposit,
+                    rate_per_second: 2 * rate_per_second,
+                    last_claim_time: now,
+                    start_time: now,
+                },
+            );
+            ink::env::set_contract_storage(
+                &(contract.subscriptions.key(), (accounts.charlie, accounts.alice)),
+                &SubscriptionV1 {
+                    total_deposited: 0,
+                    rate_per_second: 0,
+                    last_claim_time: now,
+                    start_time: now,
+                },
+            );
+
+            // Nobody owned version 1, so only the contract's own account
+            // (i.e. governance) can migrate, and creators go first
+            let governance = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.get_owner(), governance);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.migrate_creators(vec![accounts.alice]), Err(Error::NotOwner));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(governance);
+            let pairs = vec![
+                (accounts.bob, accounts.alice),
+                (accounts.frank, accounts.alice),
+                (accounts.charlie, accounts.alice),
+            ];
+            assert_eq!(contract.migrate_subscriptions(pairs.clone()), Err(Error::CreatorNotFound));
+
+            // Unknown and already-migrated accounts are skipped, and the
+            // creator list is rebuilt without holes
+            let creators = vec![accounts.alice, accounts.charlie];
+            assert_eq!(contract.migrate_creators(creators.clone()), Ok(1));
+            assert_eq!(contract.migrate_creators(creators), Ok(0));
+            assert_eq!(contract.get_creator_profile(accounts.alice).unwrap().name, "Alice");
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            contract.register_creator("Eve".to_string()).unwrap();
+            let (page, total) = contract.get_creator_list(0, 10);
+            assert_eq!(total, 2);
+            assert_eq!(page[0].0, accounts.alice);
+            assert_eq!(page[1].0, accounts.eve);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(governance);
+            assert_eq!(contract.migrate_subscriptions(pairs.clone()), Ok(2));
+            assert_eq!(contract.migrate_subscriptions(pairs), Ok(0));
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.total_deposited, deposit);
+            assert_eq!(subscription.price_per_period, deposit);
+            let legacy_plan = contract.get_plan(accounts.alice, subscription.plan_id).unwrap();
+            assert!(!legacy_plan.active);
+            assert_eq!(legacy_plan.price, deposit);
+
+            // Each old rate gets a retired plan priced like its subscriptions
+            let faster = contract
+                .get_subscription(accounts.frank, accounts.alice)
+                .unwrap();
+            assert_ne!(faster.plan_id, subscription.plan_id);
+            assert_eq!(faster.price_per_period, 2 * deposit);
+            assert_eq!(
+                contract.get_plan(accounts.alice, faster.plan_id).unwrap().price,
+                2 * deposit
+            );
+
+            // The cancelled subscription is dropped: no access, no index entry
+            assert_eq!(
+                contract.get_subscription(accounts.charlie, accounts.alice),
+                Err(Error::SubscriptionNotFound)
+            );
+            assert!(contract.check_access(accounts.charlie, accounts.alice, 0).is_err());
+            assert_eq!(contract.get_subscribers(accounts.alice, 0, 10).1, 2);
+
+            // ...so the fan can subscribe again
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let plan_id = create_monthly_plan(&mut contract, deposit);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(deposit);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            assert_eq!(contract.get_subscribers(accounts.alice, 0, 10).1, 3);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(governance);
+
+            // The old rate keeps streaming: half the month vests half the deposit
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                now + 15 * 24 * 60 * 60 * 1000,
+            );
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.claimable, deposit / 2);
+
+            contract.complete_migration().unwrap();
+            assert_eq!(contract.get_storage_version(), STORAGE_VERSION);
+            assert_eq!(contract.complete_migration(), Err(Error::AlreadyMigrated));
+
+            // Governance hands the contract to its operator
+            contract.transfer_ownership(accounts.django).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.accept_ownership().unwrap();
+            assert_eq!(contract.get_owner(), accounts.django);
+            assert_eq!(contract.get_treasury(), accounts.django);
+
+            // Upgrades are owner-only
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.upgrade(H256::zero()), Err(Error::NotOwner));
+        }
+
+        /// Test the core lifecycle emits events with indexed H160 topics
+        #[ink::test]
+        fn test_events_emitted() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+            contract.add_exclusive_content("QmContent".to_string()).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            );
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            contract.cancel_subscription(accounts.alice).unwrap();
+
+            // CreatorRegistered, PlanCreated, ContentAdded, SubscriptionCreated,
+            // SubscriptionCancelled (nothing vested, so no EarningsClaimed)
+            let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(events.len(), 5);
+
+            // Signature topic plus the indexed creator
+            assert_eq!(events[0].topics.len(), 2);
+            // Signature topic plus the indexed fan and creator
+            assert_eq!(events[3].topics.len(), 3);
+            assert_eq!(events[4].topics.len(), 3);
+        }
+
+        /// Test access control - only creators can add content
+        #[ink::test]
+        fn test_content_access_control() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Bob (not a creator) tries to add content - should fail
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let content_hash = "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u".to_string();
+            let result = contract.add_exclusive_content(content_hash);
+            assert_eq!(result, Err(Error::CreatorNotFound));
+        }
+
+        /// Test vesting math accuracy
+        #[ink::test]
+        fn test_vesting_calculations() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Set up subscription with known values
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128; // Exactly 2,592,000 Planck (for easy math)
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+
+            // Verify the rate is stored as an exact fraction of the plan price
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.price_per_period, monthly_rate);
+            assert_eq!(subscription.billing_period, BillingPeriod::Monthly);
+            assert_eq!(subscription.billing_period.duration_ms(), 30 * 24 * 60 * 60 * 1000);
+
+            // Simulate exactly 1000 seconds passing
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 1_000_000,
+            ); // +1000 seconds
+
+            // Alice claims - should get exactly 1,000,000,000 Planck
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let claimed = contract.claim_earnings(accounts.bob).unwrap();
+            assert_eq!(claimed, 1_000_000_000); // 1000 seconds * 1,000,000 Planck/second
+        }
+
+        /// Test tiny plans stream their exact price with no truncation or dust
+        #[ink::test]
+        fn test_fixed_point_vesting_has_no_dust() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // 1,000 Planck per 30 days - under the old per-second rate this was 0
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let price = 1_000u128;
+            let plan_id = create_monthly_plan(&mut contract, price);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(price);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            let period_ms = 30 * 24 * 60 * 60 * 1000;
+
+            // Claim at awkward intervals; fractions carry over between claims
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let mut total_claimed = 0;
+            for elapsed in [1, 3_600_000, 3_600_001, 86_400_007, 1_000_000_000, period_ms] {
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                    initial_time + elapsed,
+                );
+                total_claimed += contract.claim_earnings(accounts.bob).unwrap();
+
+                // Vested amount always equals the exact pro-rata share, rounded down
+                let expected = price * u128::from(elapsed) / u128::from(period_ms);
+                assert_eq!(total_claimed, expected);
+            }
+
+            // Exactly the agreed price after one full period - nothing left behind
+            assert_eq!(total_claimed, price);
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.outstanding(), 0);
+            assert_eq!(subscription.paid_until(), initial_time + period_ms);
+        }
+
+        /// Test creators can define plans and manage them
+        #[ink::test]
+        fn test_plan_management() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Non-creators can't sell plans
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let result =
+                contract.create_plan("Pro".to_string(), 10, BillingPeriod::Monthly, Vec::new());
+            assert_eq!(result, Err(Error::CreatorNotFound));
+
+            // Alice creates two tiers
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let supporter = create_monthly_plan(&mut contract, 5_000_000_000_000);
+            let patron = contract
+                .create_plan(
+                    "Patron".to_string(),
+                    50_000_000_000_000,
+                    BillingPeriod::Monthly,
+                    vec!["Monthly Q&A".to_string()],
+                )
+                .unwrap();
+            assert_eq!((supporter, patron), (0, 1));
+
+            // Zero-priced plans are rejected
+            let result =
+                contract.create_plan("Free".to_string(), 0, BillingPeriod::Monthly, Vec::new());
+            assert_eq!(result, Err(Error::InvalidPlan));
+            let result =
+                contract.create_plan("Never".to_string(), 10, BillingPeriod::Days(0), Vec::new());
+            assert_eq!(result, Err(Error::InvalidPlan));
+
+            let plans = contract.get_creator_plans(accounts.alice);
+            assert_eq!(plans.len(), 2);
+            assert_eq!(plans[1].1.perks, vec!["Monthly Q&A".to_string()]);
+
+            // Retired plans stay listed but can't be joined
+            contract.set_plan_active(patron, false).unwrap();
+            assert!(!contract.get_plan(accounts.alice, patron).unwrap().active);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                50_000_000_000_000,
+            );
+            let result = contract.subscribe(accounts.alice, patron, None);
+            assert_eq!(result, Err(Error::PlanInactive));
+        }
+
+        /// Test each plan streams its price over its own billing period
+        #[ink::test]
+        fn test_billing_periods() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Twelve calendar months make exactly one calendar year
+            assert_eq!(
+                BillingPeriod::CalendarMonth.duration_ms() * 12,
+                BillingPeriod::CalendarYear.duration_ms()
+            );
+            assert_eq!(
+                BillingPeriod::Days(7).duration_ms(),
+                BillingPeriod::Weekly.duration_ms()
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let price = 700_000_000_000;
+            let weekly = contract
+                .create_plan("Weekly".to_string(), price, BillingPeriod::Weekly, Vec::new())
+                .unwrap();
+            let yearly = contract
+                .create_plan("Annual".to_string(), price * 50, BillingPeriod::Yearly, Vec::new())
+                .unwrap();
+            assert_eq!(
+                contract.get_plan(accounts.alice, yearly).unwrap().period,
+                BillingPeriod::Yearly
+            );
+
+            // Bob pays for two weeks
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(price * 2);
+            contract.subscribe(accounts.alice, weekly, None).unwrap();
+
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            let week_ms = BillingPeriod::Weekly.duration_ms();
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.billing_period, BillingPeriod::Weekly);
+            assert_eq!(subscription.paid_until(), initial_time + 2 * week_ms);
+
+            // Editing the plan later doesn't change Bob's running subscription
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract
+                .update_plan(
+                    weekly,
+                    "Weekly".to_string(),
+                    price,
+                    BillingPeriod::Monthly,
+                    Vec::new(),
+                )
+                .unwrap();
+
+            // After one week exactly one weekly price has vested
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + week_ms,
+            );
+            let claimed = contract.claim_earnings(accounts.bob).unwrap();
+            assert_eq!(claimed, price);
+
+            // Cancelling refunds the unused second week
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let refund = contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(refund, price);
+        }
+
+        /// Test subscribe rejects payments that don't match the plan
+        #[ink::test]
+        fn test_subscribe_requires_plan_payment() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
 
-            // Verify creator exists and has content
-            let profile = self.creators.get(creator).ok_or(Error::CreatorNotFound)?;
+            // Unknown plan
+            let result = contract.subscribe(accounts.alice, plan_id + 1, None);
+            assert_eq!(result, Err(Error::PlanNotFound));
 
-            // Check if fan has active subscription to this creator
-            // This is the core gating mechanism
-            let subscription_key = (fan, creator);
-            if self.subscriptions.get(subscription_key).is_none() {
-                return Err(Error::SubscriptionRequired);
-            }
+            // Less than one billing period
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                1_000_000_000_000,
+            );
+            let result = contract.subscribe(accounts.alice, plan_id, None);
+            assert_eq!(result, Err(Error::InsufficientPayment));
 
-            // Return content hash if available, or error if creator hasn't uploaded content yet
-            profile.content_hash.ok_or(Error::CreatorNotFound)
+            // One and a half billing periods
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                7_500_000_000_000,
+            );
+            let result = contract.subscribe(accounts.alice, plan_id, None);
+            assert_eq!(result, Err(Error::PaymentMismatch));
+
+            // Exactly two billing periods
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                10_000_000_000_000,
+            );
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.plan_id, plan_id);
+            assert_eq!(subscription.total_deposited, 10_000_000_000_000);
         }
 
-        // 📋 OPTIMIZED QUERY FUNCTIONS
-        // These functions provide efficient data access for the frontend
+        /// Test topping up settles the stream first and extends the runway
+        #[ink::test]
+        fn test_top_up_extends_runway() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-        /// Get list of all registered creators with their profiles
-        /// This is used by the frontend to display the creator discovery page
-        ///
-        /// **Key Concept: Storage Iteration Challenges**
-        /// In traditional databases, you can easily "SELECT * FROM creators".
-        /// In blockchain storage, iteration is more complex and expensive.
-        ///
-        /// **Current Implementation**:
-        /// This is a placeholder that returns empty for now. In a production system,
-        /// we would need to maintain a separate Vec<AccountId> of creator addresses
-        /// to make iteration possible and efficient.
-        ///
-        /// **Better Architecture** (for future implementation):
-        /// ```rust
-        /// // Add to storage:
-        /// creator_addresses: Vec<AccountId>,
-        ///
-        /// // In register_creator():
-        /// self.creator_addresses.push(caller);
-        ///
-        /// // In get_creator_list():
-        /// let mut creators = Vec::new();
-        /// for address in &self.creator_addresses {
-        ///     if let Some(profile) = self.creators.get(address) {
-        ///         creators.push((*address, profile));
-        ///     }
-        /// }
-        /// ```
-        ///
-        /// **Gas Considerations**:
-        /// - Large lists can exceed block gas limits
-        /// - Consider pagination for production use
-        /// - Frontend should cache results when possible
-        ///
-        /// Returns:
-        /// - Vector of (AccountId, CreatorProfile) pairs
-        /// - Currently returns empty vector (TODO: implement proper iteration)
-        #[ink(message)]
-        pub fn get_creator_list(&self) -> Vec<(H160, CreatorProfile)> {
-            let creators = Vec::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128; // 1,000,000 Planck/second
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
 
-            // TODO: Implement proper iteration over creators
-            // This requires maintaining a separate list of creator addresses
-            // For now, return empty vector to avoid compilation issues
+            // Topping up without a subscription fails
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            assert_eq!(
+                contract.top_up(accounts.alice),
+                Err(Error::SubscriptionNotFound)
+            );
 
-            // In a full implementation, this would look like:
-            // for address in &self.creator_addresses {
-            //     if let Some(profile) = self.creators.get(address) {
-            //         creators.push((*address, profile));
-            //     }
-            // }
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
 
-            creators
-        }
-    }
+            // Let the stream run dry: 31 days pass on a 30-day deposit
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            let now = initial_time + 31 * 24 * 60 * 60 * 1000;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(now);
 
-    // 🧪 UNIT TESTS
-    // These tests verify our contract logic works correctly
-    // Run with: cargo test
+            // Bob tops up another month - runway restarts from now, not from the dry spell
+            let paid_until = contract.top_up(accounts.alice).unwrap();
+            assert_eq!(paid_until, now + 30 * 24 * 60 * 60 * 1000);
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+            // The whole first deposit was settled as vested during the top-up
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.total_deposited, 2 * monthly_rate);
+            assert_eq!(subscription.total_vested, monthly_rate);
+            assert_eq!(subscription.start_time, initial_time);
 
-        /// Test that creators can register successfully
+            // Only time after the top-up vests on top of the first deposit
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(now + 1_000_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let claimed = contract.claim_earnings(accounts.bob).unwrap();
+            assert_eq!(claimed, monthly_rate + 1_000_000_000); // + 1000 seconds of streaming
+        }
+
+        /// Test a fan can resubscribe after cancelling and keeps their history
         #[ink::test]
-        fn test_creator_registration() {
+        fn test_resubscribe_after_cancel() {
             let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Register a creator
-            let result = contract.register_creator("Alice".to_string());
-            assert!(result.is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
 
-            // Verify creator count increased
-            assert_eq!(contract.get_creator_count(), 1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            );
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
 
-            // Verify creator exists
-            let alice = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice;
-            assert!(contract.is_creator(alice));
+            // A funded subscription can't be opened twice
+            assert_eq!(
+                contract.subscribe(accounts.alice, plan_id, None),
+                Err(Error::SubscriptionAlreadyExists)
+            );
+
+            contract.cancel_subscription(accounts.alice).unwrap();
+
+            // Bob comes back and subscribes again
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            assert!(contract.get_subscription(accounts.bob, accounts.alice).is_ok());
+
+            let history = contract.get_subscription_history(accounts.bob, accounts.alice);
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].reason, SubscriptionEnd::Cancelled);
+            assert_eq!(history[0].plan_id, plan_id);
         }
 
-        /// Test that duplicate registration fails
+        /// Test a depleted subscription is archived when the fan restarts it
         #[ink::test]
-        fn test_duplicate_registration_fails() {
+        fn test_resubscribe_after_depletion() {
             let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Register creator once
-            let result1 = contract.register_creator("Alice".to_string());
-            assert!(result1.is_ok());
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128; // 1,000,000 Planck/second
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
 
-            // Try to register again - should fail
-            let result2 = contract.register_creator("Alice Again".to_string());
-            assert_eq!(result2, Err(Error::CreatorAlreadyExists));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+
+            // The whole deposit streams out
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 30 * 24 * 60 * 60 * 1000,
+            );
+
+            // Restarting settles the old stream to Alice before archiving it
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, monthly_rate);
+
+            let history = contract.get_subscription_history(accounts.bob, accounts.alice);
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].reason, SubscriptionEnd::Depleted);
+            assert_eq!(history[0].refunded, 0);
+
+            let subscription = contract
+                .get_subscription(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(subscription.total_deposited, monthly_rate);
         }
 
-        /// Test subscription creation
+        /// Test the status query follows a subscription through its lifecycle
         #[ink::test]
-        fn test_subscription_creation() {
+        fn test_subscription_status() {
             let mut contract = CreatorTreasuryPop::new();
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Set up: Alice is creator, Bob is fan
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128; // 1,000,000 Planck/second
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            // Never subscribed
+            let result = contract.get_subscription_status(accounts.bob, accounts.alice);
+            assert_eq!(result, Err(Error::SubscriptionNotFound));
 
-            // Bob subscribes to Alice
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
-                5_000_000_000_000,
-            ); // 5 DOT
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
 
-            let result = contract.subscribe(accounts.alice, 5_000_000_000_000);
-            assert!(result.is_ok());
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+            let month = 30 * 24 * 60 * 60 * 1000;
 
-            // Verify subscription exists
-            let subscription = contract.get_subscription(accounts.bob, accounts.alice);
-            assert!(subscription.is_ok());
+            // A third of the way through, without anyone settling on-chain
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 10 * 24 * 60 * 60 * 1000,
+            );
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.status, SubscriptionStatus::Active);
+            assert_eq!(overview.claimable, monthly_rate / 3);
+            assert_eq!(overview.remaining, monthly_rate - monthly_rate / 3);
+            assert_eq!(overview.paid_until, initial_time + month);
+
+            // Fully streamed but not restarted yet
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 2 * month,
+            );
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.status, SubscriptionStatus::Depleted);
+            assert_eq!(overview.claimable, monthly_rate);
+            assert_eq!(overview.remaining, 0);
+            assert_eq!(overview.paid_until, initial_time + month);
+
+            // Restart and cancel - the archive decides the status
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            contract.cancel_subscription(accounts.alice).unwrap();
+            let overview = contract
+                .get_subscription_status(accounts.bob, accounts.alice)
+                .unwrap();
+            assert_eq!(overview.status, SubscriptionStatus::Cancelled);
+            assert_eq!(overview.claimable, 0);
+            assert_eq!(overview.remaining, 0);
+            assert_eq!(overview.paid_until, initial_time + 2 * month);
         }
 
-        /// Test earnings claiming with time-based vesting
+        /// Test a minimum commitment stops subscribe-read-cancel refunds
         #[ink::test]
-        fn test_claim_earnings() {
+        fn test_minimum_commitment() {
             let mut contract = CreatorTreasuryPop::new();
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Set up subscription
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128;
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+            contract.set_plan_commitment(plan_id, 2).unwrap();
+            assert_eq!(
+                contract.get_plan(accounts.alice, plan_id).unwrap().min_commitment_periods,
+                2
+            );
 
+            // The deposit must cover the whole commitment
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            let result = contract.subscribe(accounts.alice, plan_id, None);
+            assert_eq!(result, Err(Error::InsufficientPayment));
+
             ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
-                5_000_000_000_000,
+                monthly_rate * 3,
             );
-            contract
-                .subscribe(accounts.alice, 5_000_000_000_000)
-                .unwrap();
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
 
-            // Simulate time passing (advance block timestamp)
+            // Read and cancel an hour later - only the uncommitted period comes back
             let initial_time =
                 ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
-                initial_time + 3600000,
-            ); // +1 hour
-
-            // Alice claims earnings
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            let result = contract.claim_earnings(accounts.bob);
-            assert!(result.is_ok());
+                initial_time + 60 * 60 * 1000,
+            );
+            let refund = contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(refund, monthly_rate);
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, monthly_rate * 2);
 
-            let claimed_amount = result.unwrap();
-            assert!(claimed_amount > 0); // Should have vested some amount
+            // Past the commitment, cancelling refunds the unused time as usual
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                monthly_rate * 3,
+            );
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 60 * 60 * 1000 + 75 * 24 * 60 * 60 * 1000,
+            );
+            let refund = contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(refund, monthly_rate / 2);
         }
 
-        /// Test subscription cancellation with refunds
+        /// Test cancelling after a claim doesn't count the claimed amount twice
         #[ink::test]
-        fn test_cancel_subscription() {
+        fn test_cancel_after_claim_refunds_fairly() {
             let mut contract = CreatorTreasuryPop::new();
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Set up subscription
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128; // 1,000,000 Planck/second
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
 
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
-                5_000_000_000_000,
-            );
-            contract
-                .subscribe(accounts.alice, 5_000_000_000_000)
-                .unwrap();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
 
-            // Simulate some time passing
+            let day = 24 * 60 * 60 * 1000;
             let initial_time =
                 ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+
+            // Alice claims after 10 days
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
-                initial_time + 1800000,
-            ); // +30 minutes
+                initial_time + 10 * day,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let claimed = contract.claim_earnings(accounts.bob).unwrap();
+            assert_eq!(claimed, 864_000_000_000);
 
-            // Bob cancels subscription
-            let result = contract.cancel_subscription(accounts.alice);
-            assert!(result.is_ok());
+            // Bob cancels after 15 days - he paid for 15 days, not 25
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 15 * day,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let refund = contract.cancel_subscription(accounts.alice).unwrap();
+            assert_eq!(refund, 1_296_000_000_000);
 
-            let refund_amount = result.unwrap();
-            assert!(refund_amount > 0); // Should get partial refund
+            // Alice was paid for the remaining 5 days as part of the cancellation
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, 1_296_000_000_000);
 
-            // Verify subscription is removed
-            let subscription = contract.get_subscription(accounts.bob, accounts.alice);
-            assert!(subscription.is_err());
+            let history = contract.get_subscription_history(accounts.bob, accounts.alice);
+            assert_eq!(history[0].total_claimed + history[0].refunded, monthly_rate);
         }
 
-        /// Test content management and gating
+        /// Test deposited = claimed + refunded + outstanding at every step
         #[ink::test]
-        fn test_content_gating() {
+        fn test_ledger_invariant() {
             let mut contract = CreatorTreasuryPop::new();
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Alice registers as creator and adds content
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             contract.register_creator("Alice".to_string()).unwrap();
+            let plan_id = create_monthly_plan(&mut contract, 5_000_000_000_000);
+
+            let assert_balanced = |contract: &CreatorTreasuryPop| {
+                let subscription = contract
+                    .get_subscription(accounts.bob, accounts.alice)
+                    .unwrap();
+                assert_eq!(
+                    subscription.total_deposited,
+                    subscription.total_claimed
+                        + subscription.total_refunded
+                        + subscription.outstanding()
+                );
+                assert_eq!(
+                    subscription.outstanding(),
+                    subscription.claimable() + subscription.unvested()
+                );
+                assert!(subscription.check_ledger().is_ok());
+            };
 
-            let content_hash = "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u".to_string();
-            let result = contract.add_exclusive_content(content_hash.clone());
-            assert!(result.is_ok());
-
-            // Bob tries to access content without subscription - should fail
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            let content_result = contract.get_creator_content(accounts.alice);
-            assert_eq!(content_result, Err(Error::SubscriptionRequired));
-
-            // Bob subscribes to Alice
             ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
                 5_000_000_000_000,
             );
-            contract
-                .subscribe(accounts.alice, 5_000_000_000_000)
-                .unwrap();
+            contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            assert_balanced(&contract);
 
-            // Now Bob can access content
-            let content_result = contract.get_creator_content(accounts.alice);
-            assert!(content_result.is_ok());
-            assert_eq!(content_result.unwrap(), content_hash);
+            let initial_time =
+                ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
+
+            // Claim, top up and claim again at odd, sub-second offsets
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 3_600_500,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.claim_earnings(accounts.bob).unwrap();
+            assert_balanced(&contract);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 7_200_999,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_234_567);
+            contract.top_up(accounts.alice).unwrap();
+            assert_balanced(&contract);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 86_400_001,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.claim_earnings(accounts.bob).unwrap();
+            assert_balanced(&contract);
+
+            // After cancelling, every Planck is accounted for
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.cancel_subscription(accounts.alice).unwrap();
+            let history = contract.get_subscription_history(accounts.bob, accounts.alice);
+            assert_eq!(
+                history[0].total_deposited,
+                history[0].total_claimed + history[0].refunded
+            );
+            assert_eq!(history[0].total_deposited, 5_000_001_234_567);
         }
 
-        /// Test access control - only creators can add content
+        /// Test creator discovery is listed in registration order and paginated
         #[ink::test]
-        fn test_content_access_control() {
+        fn test_creator_list_pagination() {
             let mut contract = CreatorTreasuryPop::new();
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Bob (not a creator) tries to add content - should fail
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            let content_hash = "QmX7M9CiYXjVQX8Z2HvjKq4XvLqWjAoKGmhq9F3nR8sT4u".to_string();
-            let result = contract.add_exclusive_content(content_hash);
-            assert_eq!(result, Err(Error::CreatorNotFound));
+            // Nobody registered yet
+            let (page, total) = contract.get_creator_list(0, 10);
+            assert!(page.is_empty());
+            assert_eq!(total, 0);
+
+            for (account, name) in [
+                (accounts.alice, "Alice"),
+                (accounts.bob, "Bob"),
+                (accounts.charlie, "Charlie"),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+                contract.register_creator(name.to_string()).unwrap();
+            }
+
+            let (page, total) = contract.get_creator_list(0, 2);
+            assert_eq!(total, 3);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page[0].0, accounts.alice);
+            assert_eq!(page[1].1.name, "Bob");
+
+            // Last page is short, pages past the end are empty
+            let (page, _) = contract.get_creator_list(2, 2);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].0, accounts.charlie);
+            let (page, _) = contract.get_creator_list(5, 2);
+            assert!(page.is_empty());
         }
 
-        /// Test vesting math accuracy
+        /// Test subscriber and fan indexes follow subscribe / cancel
         #[ink::test]
-        fn test_vesting_calculations() {
+        fn test_subscription_indexes() {
             let mut contract = CreatorTreasuryPop::new();
             let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
 
-            // Set up subscription with known values
+            // Alice and Charlie are creators
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
             contract.register_creator("Alice".to_string()).unwrap();
+            let alice_plan = create_monthly_plan(&mut contract, 5_000_000_000_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.register_creator("Charlie".to_string()).unwrap();
+            let charlie_plan = create_monthly_plan(&mut contract, 5_000_000_000_000);
 
+            // Bob follows both, Django and Eve follow Alice
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(
+                5_000_000_000_000,
+            );
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            let monthly_rate = 2_592_000_000_000u128; // Exactly 2,592,000 Planck (for easy math)
-            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
-            contract.subscribe(accounts.alice, monthly_rate).unwrap();
+            contract.subscribe(accounts.alice, alice_plan, None).unwrap();
+            contract.subscribe(accounts.charlie, charlie_plan, None).unwrap();
+            for fan in [accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(fan);
+                contract.subscribe(accounts.alice, alice_plan, None).unwrap();
+            }
 
-            // Verify rate_per_second calculation
-            let subscription = contract
-                .get_subscription(accounts.bob, accounts.alice)
-                .unwrap();
-            let expected_rate_per_second = monthly_rate / (30 * 24 * 60 * 60); // Should be 1 Planck/second
-            assert_eq!(subscription.rate_per_second, expected_rate_per_second);
+            let (page, total) = contract.get_subscribers(accounts.alice, 0, 10);
+            assert_eq!(total, 3);
+            assert_eq!(page.len(), 3);
+            assert_eq!(page[0].0, accounts.bob);
+
+            let (page, total) = contract.get_fan_subscriptions(accounts.bob, 0, 10);
+            assert_eq!(total, 2);
+            assert_eq!(page[0].0, accounts.alice);
+            assert_eq!(page[1].0, accounts.charlie);
+
+            // Bob leaves Alice - Eve is swapped into his slot
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.cancel_subscription(accounts.alice).unwrap();
+
+            let (page, total) = contract.get_subscribers(accounts.alice, 0, 10);
+            assert_eq!(total, 2);
+            let fans: Vec<H160> = page.iter().map(|(fan, _)| *fan).collect();
+            assert_eq!(fans, vec![accounts.eve, accounts.django]);
+
+            let (page, total) = contract.get_fan_subscriptions(accounts.bob, 0, 10);
+            assert_eq!(total, 1);
+            assert_eq!(page[0].0, accounts.charlie);
+
+            // Eve can still leave cleanly from her new slot
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            contract.cancel_subscription(accounts.alice).unwrap();
+            let (page, total) = contract.get_subscribers(accounts.alice, 0, 10);
+            assert_eq!(total, 1);
+            assert_eq!(page[0].0, accounts.django);
+        }
+
+        /// Test a creator can claim from all subscribers at once or in batches
+        #[ink::test]
+        fn test_claim_all_and_batches() {
+            let mut contract = CreatorTreasuryPop::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.register_creator("Alice".to_string()).unwrap();
+            let monthly_rate = 2_592_000_000_000u128; // 1,000,000 Planck/second
+            let plan_id = create_monthly_plan(&mut contract, monthly_rate);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(monthly_rate);
+            for fan in [accounts.bob, accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(fan);
+                contract.subscribe(accounts.alice, plan_id, None).unwrap();
+            }
 
-            // Simulate exactly 1000 seconds passing
             let initial_time =
                 ink::env::test::get_block_timestamp::<ink::env::DefaultEnvironment>();
             ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
                 initial_time + 1_000_000,
             ); // +1000 seconds
 
-            // Alice claims - should get exactly 1000 Planck
+            // Settle the first two fans, then the rest
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            let claimed = contract.claim_earnings(accounts.bob).unwrap();
-            assert_eq!(claimed, 1000); // 1000 seconds * 1 Planck/second
+            let (claimed, cursor) = contract.claim_batch(0, 2).unwrap();
+            assert_eq!((claimed, cursor), (2_000_000_000, 2));
+            let (claimed, cursor) = contract.claim_batch(cursor, 2).unwrap();
+            assert_eq!((claimed, cursor), (1_000_000_000, 3));
+
+            // Another 1000 seconds, then claim everything in one go
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                initial_time + 2_000_000,
+            );
+            assert_eq!(contract.claim_all().unwrap(), 3_000_000_000);
+            assert_eq!(contract.claim_all().unwrap(), 0);
+
+            let profile = contract.get_creator_profile(accounts.alice).unwrap();
+            assert_eq!(profile.total_earned, 6_000_000_000);
+
+            // Non-creators can't batch claim
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.claim_all(), Err(Error::CreatorNotFound));
         }
     }
 }